@@ -0,0 +1,251 @@
+//! Run this file with `cargo test --test binary_heap`.
+
+//! TODO: Implement a min-heap and a max-heap as
+//! [binary heaps](https://en.wikipedia.org/wiki/Binary_heap) stored in a `Vec`.
+//!
+//! The children of the element at index `i` are at indices `2 * i + 1` and `2 * i + 2`. Every
+//! element must be smaller (min-heap) or larger (max-heap) than or equal to its children, so the
+//! root is always the smallest (largest) element.
+//!
+//! - `push`: appends the value and sifts it up, swapping it with its parent while it belongs
+//!   above it.
+//! - `pop`: removes and returns the root. Move the last element to the root and sift it down,
+//!   swapping it with its smaller (larger) child while it belongs below it.
+//! - `peek`: returns the root without removing it.
+//! - `len`: returns the number of elements.
+//!
+//! Both heaps work the same way, except for the comparison. Try to share the implementation
+//! instead of writing it twice.
+
+/// A binary heap in which `above(a, b)` holds whenever `a` may be stored above `b`.
+struct Heap<T: Ord> {
+    items: Vec<T>,
+    above: fn(&T, &T) -> bool,
+}
+
+impl<T: Ord> Heap<T> {
+    fn new(above: fn(&T, &T) -> bool) -> Self {
+        Self {
+            items: vec![],
+            above,
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        self.items.push(value);
+
+        let mut index = self.items.len() - 1;
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if (self.above)(&self.items[parent], &self.items[index]) {
+                break;
+            }
+            self.items.swap(parent, index);
+            index = parent;
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let root = self.items.swap_remove(0);
+
+        let mut index = 0;
+        loop {
+            let mut top = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < self.items.len() && !(self.above)(&self.items[top], &self.items[child])
+                {
+                    top = child;
+                }
+            }
+            if top == index {
+                break;
+            }
+            self.items.swap(index, top);
+            index = top;
+        }
+
+        Some(root)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+struct MinHeap<T: Ord> {
+    heap: Heap<T>,
+}
+
+impl<T: Ord> MinHeap<T> {
+    fn new() -> Self {
+        Self {
+            heap: Heap::new(|a, b| a <= b),
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        self.heap.push(value)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+struct MaxHeap<T: Ord> {
+    heap: Heap<T>,
+}
+
+impl<T: Ord> MaxHeap<T> {
+    fn new() -> Self {
+        Self {
+            heap: Heap::new(|a, b| a >= b),
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        self.heap.push(value)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{Heap, MaxHeap, MinHeap};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_heap_property<T: Ord + std::fmt::Debug>(heap: &Heap<T>) {
+        for (index, item) in heap.items.iter().enumerate().skip(1) {
+            let parent = &heap.items[(index - 1) / 2];
+            assert!((heap.above)(parent, item), "{parent:?} above {item:?}");
+        }
+    }
+
+    fn heap_sort(values: &[i32]) -> Vec<i32> {
+        let mut heap = MinHeap::new();
+        for &value in values {
+            heap.push(value);
+        }
+        std::iter::from_fn(|| heap.pop()).collect()
+    }
+
+    #[test]
+    fn empty_heaps() {
+        let mut min: MinHeap<u32> = MinHeap::new();
+        let mut max: MaxHeap<u32> = MaxHeap::new();
+        assert_eq!(min.len(), 0);
+        assert_eq!(min.peek(), None);
+        assert_eq!(min.pop(), None);
+        assert_eq!(max.peek(), None);
+        assert_eq!(max.pop(), None);
+    }
+
+    #[test]
+    fn min_heap_order() {
+        let mut heap = MinHeap::new();
+        for value in [5, 3, 8, 1, 9, 2] {
+            heap.push(value);
+        }
+        assert_eq!(heap.len(), 6);
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(3));
+        assert_eq!(heap.len(), 3);
+    }
+
+    #[test]
+    fn max_heap_order() {
+        let mut heap = MaxHeap::new();
+        for value in ["pear", "apple", "plum", "fig"] {
+            heap.push(value);
+        }
+        assert_eq!(heap.peek(), Some(&"plum"));
+        assert_eq!(heap.pop(), Some("plum"));
+        assert_eq!(heap.pop(), Some("pear"));
+        assert_eq!(heap.pop(), Some("fig"));
+        assert_eq!(heap.pop(), Some("apple"));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut heap = MaxHeap::new();
+        for value in [2, 7, 2, 7, 7] {
+            heap.push(value);
+        }
+        let popped: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, vec![7, 7, 7, 2, 2]);
+    }
+
+    #[test]
+    fn heap_property_under_random_operations() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut min = MinHeap::new();
+        let mut max = MaxHeap::new();
+        // The values each heap should hold, kept sorted.
+        let mut min_values: Vec<i32> = vec![];
+        let mut max_values: Vec<i32> = vec![];
+
+        for _ in 0..2000 {
+            if rng.random_bool(0.6) {
+                let value = rng.random_range(-100..100);
+                min.push(value);
+                max.push(value);
+                min_values.push(value);
+                max_values.push(value);
+                min_values.sort();
+                max_values.sort();
+            } else {
+                let expected_min = (!min_values.is_empty()).then(|| min_values.remove(0));
+                assert_eq!(min.pop(), expected_min);
+                assert_eq!(max.pop(), max_values.pop());
+            }
+
+            assert_eq!(min.len(), min_values.len());
+            assert_eq!(max.len(), max_values.len());
+            assert_eq!(max.peek(), max_values.last());
+            assert_heap_property(&min.heap);
+            assert_heap_property(&max.heap);
+        }
+    }
+
+    #[test]
+    fn heap_sort_sorts() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let values: Vec<i32> = (0..500).map(|_| rng.random_range(-1000..1000)).collect();
+        let mut expected = values.clone();
+        expected.sort();
+        assert_eq!(heap_sort(&values), expected);
+        assert_eq!(heap_sort(&[]), Vec::<i32>::new());
+        assert_eq!(heap_sort(&[3, 1, 2]), vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,143 @@
+//! Run this file with `cargo test --test binary_indexed_tree`.
+
+//! TODO: Implement a [Fenwick tree](https://en.wikipedia.org/wiki/Fenwick_tree) (binary indexed
+//! tree) over `n` values, which all start at zero.
+//!
+//! - `update`: adds `delta` to the value at `index`.
+//! - `prefix_sum`: returns the sum of the values at indices `0..=index`.
+//! - `range_sum`: returns the sum of the values at indices `lo..=hi`, or 0 if `lo > hi`.
+//!
+//! All operations should run in O(log n). Indices outside of the values should panic.
+//!
+//! Hint: the tree is easiest to implement with 1-based indices. Entry `i` stores the sum of the
+//! `i & i.wrapping_neg()` values ending at `i`, i.e. as many values as the lowest set bit of `i`
+//! says. A prefix sum adds up the entries while removing the lowest set bit from `i`, and an
+//! update changes the entries while adding the lowest set bit to `i`.
+
+struct FenwickTree {
+    /// The 1-based tree, `data[0]` is unused.
+    data: Vec<i64>,
+}
+
+/// The value of the lowest set bit of `i`.
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl FenwickTree {
+    fn new(n: usize) -> Self {
+        Self {
+            data: vec![0; n + 1],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len() - 1
+    }
+
+    fn update(&mut self, index: usize, delta: i64) {
+        assert!(index < self.len(), "index {index} out of bounds");
+
+        let mut i = index + 1;
+        while i < self.data.len() {
+            self.data[i] += delta;
+            i += lowest_bit(i);
+        }
+    }
+
+    fn prefix_sum(&self, index: usize) -> i64 {
+        assert!(index < self.len(), "index {index} out of bounds");
+
+        let mut sum = 0;
+        let mut i = index + 1;
+        while i > 0 {
+            sum += self.data[i];
+            i -= lowest_bit(i);
+        }
+        sum
+    }
+
+    fn range_sum(&self, lo: usize, hi: usize) -> i64 {
+        if lo > hi {
+            return 0;
+        }
+        let before = if lo == 0 { 0 } else { self.prefix_sum(lo - 1) };
+        self.prefix_sum(hi) - before
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::FenwickTree;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn starts_at_zero() {
+        let tree = FenwickTree::new(5);
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.prefix_sum(4), 0);
+        assert_eq!(tree.range_sum(1, 3), 0);
+    }
+
+    #[test]
+    fn prefix_sums_after_updates() {
+        let mut tree = FenwickTree::new(8);
+        for (index, value) in [3, 2, -1, 6, 5, 4, -3, 3].into_iter().enumerate() {
+            tree.update(index, value);
+        }
+        assert_eq!(tree.prefix_sum(0), 3);
+        assert_eq!(tree.prefix_sum(3), 10);
+        assert_eq!(tree.prefix_sum(7), 19);
+
+        tree.update(3, -6);
+        assert_eq!(tree.prefix_sum(2), 4);
+        assert_eq!(tree.prefix_sum(3), 4);
+        assert_eq!(tree.prefix_sum(7), 13);
+    }
+
+    #[test]
+    fn range_sums() {
+        let mut tree = FenwickTree::new(6);
+        for (index, value) in [1, 2, 3, 4, 5, 6].into_iter().enumerate() {
+            tree.update(index, value);
+        }
+        assert_eq!(tree.range_sum(0, 5), 21);
+        assert_eq!(tree.range_sum(2, 4), 12);
+        assert_eq!(tree.range_sum(3, 3), 4);
+        assert_eq!(tree.range_sum(4, 2), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn update_out_of_bounds() {
+        FenwickTree::new(3).update(3, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn empty_tree() {
+        FenwickTree::new(0).prefix_sum(0);
+    }
+
+    #[test]
+    fn matches_naive_scan() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let n = 100;
+        let mut tree = FenwickTree::new(n);
+        let mut values = vec![0i64; n];
+
+        for _ in 0..2000 {
+            let index = rng.random_range(0..n);
+            let delta = rng.random_range(-50..50);
+            tree.update(index, delta);
+            values[index] += delta;
+
+            let lo = rng.random_range(0..n);
+            let hi = rng.random_range(lo..n);
+            assert_eq!(tree.range_sum(lo, hi), values[lo..=hi].iter().sum::<i64>());
+            assert_eq!(tree.prefix_sum(hi), values[..=hi].iter().sum::<i64>());
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Run this file with `cargo test --test bit_ops`.
+
+//! TODO: Implement a module named `bit_ops` with a few classic bit manipulation functions. Do not
+//! use the standard library methods that already do the same thing (`count_ones`, `reverse_bits`,
+//! `is_power_of_two`, `checked_next_power_of_two`), the tests use them to check your results.
+//!
+//! - `count_ones`: the number of set bits (popcount).
+//! - `reverse_bits`: the bits of `n` in reverse order, so bit 0 becomes bit 63.
+//! - `is_power_of_two`: whether exactly one bit is set.
+//! - `next_power_of_two`: the smallest power of two greater than or equal to `n`, or `None` if
+//!   it does not fit into a `u64`. The next power of two of `0` is `1`.
+//! - `gray_code`: converts `n` to its [Gray code](https://en.wikipedia.org/wiki/Gray_code), in
+//!   which consecutive numbers differ in exactly one bit.
+//! - `from_gray`: converts a Gray code back to the original number.
+//!
+//! Hint: `n & (n - 1)` clears the lowest set bit of `n`.
+
+mod bit_ops {
+    pub fn count_ones(mut n: u64) -> u32 {
+        let mut count = 0;
+        while n != 0 {
+            n &= n - 1;
+            count += 1;
+        }
+        count
+    }
+
+    pub fn reverse_bits(mut n: u64) -> u64 {
+        let mut reversed = 0;
+        for _ in 0..u64::BITS {
+            reversed = (reversed << 1) | (n & 1);
+            n >>= 1;
+        }
+        reversed
+    }
+
+    pub fn is_power_of_two(n: u64) -> bool {
+        n != 0 && n & (n - 1) == 0
+    }
+
+    pub fn next_power_of_two(n: u64) -> Option<u64> {
+        if n <= 1 {
+            return Some(1);
+        }
+
+        // Set every bit below the highest set bit of `n - 1`, then add one.
+        let mut m = n - 1;
+        let mut shift = 1;
+        while shift < u64::BITS {
+            m |= m >> shift;
+            shift *= 2;
+        }
+        m.checked_add(1)
+    }
+
+    pub fn gray_code(n: u64) -> u64 {
+        n ^ (n >> 1)
+    }
+
+    pub fn from_gray(mut g: u64) -> u64 {
+        let mut n = g;
+        while g != 0 {
+            g >>= 1;
+            n ^= g;
+        }
+        n
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use super::bit_ops::{
+        count_ones, from_gray, gray_code, is_power_of_two, next_power_of_two, reverse_bits,
+    };
+
+    const SAMPLES: [u64; 12] = [
+        0,
+        1,
+        2,
+        3,
+        7,
+        8,
+        1000,
+        0xdead_beef,
+        1 << 63,
+        (1 << 63) + 1,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+
+    #[test]
+    fn popcount() {
+        for n in SAMPLES.into_iter().chain(0..1000) {
+            assert_eq!(count_ones(n), n.count_ones(), "{n}");
+        }
+    }
+
+    #[test]
+    fn reverse() {
+        assert_eq!(reverse_bits(1), 1 << 63);
+        assert_eq!(reverse_bits(0b1011), 0b1101 << 60);
+        for n in SAMPLES.into_iter().chain(0..1000) {
+            assert_eq!(reverse_bits(n), n.reverse_bits(), "{n}");
+            assert_eq!(reverse_bits(reverse_bits(n)), n);
+        }
+    }
+
+    #[test]
+    fn power_of_two() {
+        assert!(!is_power_of_two(0));
+        assert!(is_power_of_two(1));
+        assert!(is_power_of_two(1 << 63));
+        assert!(!is_power_of_two(6));
+        for n in SAMPLES.into_iter().chain(0..1000) {
+            assert_eq!(is_power_of_two(n), n.is_power_of_two(), "{n}");
+        }
+    }
+
+    #[test]
+    fn next_power() {
+        assert_eq!(next_power_of_two(0), Some(1));
+        assert_eq!(next_power_of_two(5), Some(8));
+        assert_eq!(next_power_of_two(1 << 63), Some(1 << 63));
+        assert_eq!(next_power_of_two((1 << 63) + 1), None);
+        for n in SAMPLES.into_iter().chain(1..1000) {
+            assert_eq!(next_power_of_two(n), n.checked_next_power_of_two(), "{n}");
+        }
+    }
+
+    #[test]
+    fn gray_code_sequence() {
+        let codes: Vec<u64> = (0..8).map(gray_code).collect();
+        assert_eq!(codes, vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+        for n in 0..1000u64 {
+            assert_eq!((gray_code(n) ^ gray_code(n + 1)).count_ones(), 1);
+        }
+    }
+
+    #[test]
+    fn gray_code_round_trip() {
+        for n in SAMPLES.into_iter().chain(0..1000) {
+            assert_eq!(from_gray(gray_code(n)), n, "{n}");
+            assert_eq!(gray_code(from_gray(n)), n, "{n}");
+        }
+    }
+}
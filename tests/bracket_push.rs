@@ -0,0 +1,96 @@
+//! Run this file with `cargo test --test bracket_push`.
+
+//! TODO: Implement a function called `min_pushes`, which computes the minimum number of bracket
+//! characters that have to be removed from a string so that its brackets become balanced.
+//!
+//! Brackets are `()`, `[]` and `{}`, balanced in the same sense as in the `match_parentheses`
+//! exercise: every bracket is closed by a bracket of the same kind, in the right order. All other
+//! characters are ignored.
+//!
+//! For example, `"(]"` needs 2 removals, while `"(()"` and `"([)]"` need 1 and 2 respectively.
+//!
+//! Hint: with a single kind of bracket a greedy counter is enough, but mixing kinds breaks the
+//! greedy approach. Instead, find the longest balanced subsequence using dynamic programming over
+//! intervals: the first bracket of an interval is either removed, or matched with some closing
+//! bracket inside the interval, which splits the rest into two independent intervals.
+
+fn matches(open: char, close: char) -> bool {
+    matches!((open, close), ('(', ')') | ('[', ']') | ('{', '}'))
+}
+
+fn min_pushes(s: &str) -> usize {
+    let brackets: Vec<char> = s
+        .chars()
+        .filter(|c| matches!(c, '(' | ')' | '[' | ']' | '{' | '}'))
+        .collect();
+    let n = brackets.len();
+
+    // `longest[i][j]` is the length of the longest balanced subsequence of `brackets[i..j]`.
+    let mut longest = vec![vec![0usize; n + 1]; n + 1];
+
+    for start in (0..n).rev() {
+        for end in start + 1..=n {
+            let mut best = longest[start + 1][end];
+            for close in start + 1..end {
+                if matches(brackets[start], brackets[close]) {
+                    let inner = longest[start + 1][close];
+                    let rest = longest[close + 1][end];
+                    best = best.max(2 + inner + rest);
+                }
+            }
+            longest[start][end] = best;
+        }
+    }
+
+    n - longest[0][n]
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::min_pushes;
+
+    #[test]
+    fn empty_string() {
+        assert_eq!(min_pushes(""), 0);
+        assert_eq!(min_pushes("no brackets here"), 0);
+    }
+
+    #[test]
+    fn already_balanced() {
+        assert_eq!(min_pushes("()"), 0);
+        assert_eq!(min_pushes("([]{})"), 0);
+        assert_eq!(min_pushes("{[()()]}[]"), 0);
+        assert_eq!(min_pushes("fn main() { let v = vec![1]; }"), 0);
+    }
+
+    #[test]
+    fn extra_closing_brackets() {
+        assert_eq!(min_pushes("())"), 1);
+        assert_eq!(min_pushes(")()"), 1);
+        assert_eq!(min_pushes("[]]]"), 2);
+        assert_eq!(min_pushes("a(b)c)"), 1);
+    }
+
+    #[test]
+    fn extra_opening_brackets() {
+        assert_eq!(min_pushes("(()"), 1);
+        assert_eq!(min_pushes("{{}"), 1);
+    }
+
+    #[test]
+    fn mismatched_kinds() {
+        assert_eq!(min_pushes("(]"), 2);
+        assert_eq!(min_pushes("([)]"), 2);
+        assert_eq!(min_pushes("([)"), 1);
+        assert_eq!(min_pushes("{(})"), 2);
+    }
+
+    #[test]
+    fn fully_unbalanced() {
+        assert_eq!(min_pushes("(((("), 4);
+        assert_eq!(min_pushes("))]}"), 4);
+        assert_eq!(min_pushes("}}}{{{"), 6);
+        assert_eq!(min_pushes(")("), 2);
+    }
+}
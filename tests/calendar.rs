@@ -0,0 +1,195 @@
+//! Run this file with `cargo test --test calendar`.
+
+//! TODO: Implement a `Date` type for dates of the
+//! [proleptic Gregorian calendar](https://en.wikipedia.org/wiki/Proleptic_Gregorian_calendar),
+//! i.e. the Gregorian calendar extended to dates before its introduction. Years may be zero or
+//! negative, year 0 being 1 BC.
+//!
+//! - `new`: creates a date, failing if the month or the day does not exist.
+//! - `days_between`: the number of days from `a` to `b`, negative if `b` is before `a`.
+//! - `add_days`: the date `days` days later (or earlier for negative `days`).
+//!
+//! A year is a [leap year](https://en.wikipedia.org/wiki/Leap_year) if it is divisible by 4,
+//! except for years divisible by 100 that are not divisible by 400. So 2000 was a leap year, but
+//! 1900 was not.
+//!
+//! Hint: both operations are easy once you can convert a date into the number of days since a
+//! fixed date and back. The calendar repeats every 400 years, which are exactly 146097 days, and
+//! starting the year in March puts the leap day at its end.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq)]
+enum DateError {
+    InvalidMonth(u8),
+    InvalidDay { month: u8, day: u8 },
+}
+
+impl Display for DateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for DateError {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Date {
+    fn new(year: i32, month: u8, day: u8) -> Result<Date, DateError> {
+        if !(1..=12).contains(&month) {
+            return Err(DateError::InvalidMonth(month));
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(DateError::InvalidDay { month, day });
+        }
+        Ok(Date { year, month, day })
+    }
+
+    /// The number of days since 1970-01-01, negative for earlier dates.
+    fn to_days(self) -> i64 {
+        // Count the years from March, so that the leap day is the last day of a year.
+        let year = self.year as i64 - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month_from_march = (self.month as i64 + 9) % 12;
+        // The months from March to January have 31, 30, 31, 30, 31, 31, 30, 31, 30, 31 and 31
+        // days, which this formula reproduces.
+        let day_of_year = (153 * month_from_march + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        // 719468 is the number of days from 0000-03-01 to 1970-01-01.
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// The inverse of `to_days`.
+    fn from_days(days: i64) -> Date {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = (month_from_march + 2) % 12 + 1;
+        let year = era * 400 + year_of_era + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    fn add_days(&self, days: i64) -> Date {
+        Date::from_days(self.to_days() + days)
+    }
+}
+
+fn days_between(a: &Date, b: &Date) -> i64 {
+    b.to_days() - a.to_days()
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{days_between, is_leap_year, Date, DateError};
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert_eq!(Date::new(2024, 0, 1), Err(DateError::InvalidMonth(0)));
+        assert_eq!(Date::new(2024, 13, 1), Err(DateError::InvalidMonth(13)));
+        assert_eq!(Date::new(2024, 4, 31), Err(DateError::InvalidDay { month: 4, day: 31 }));
+        assert_eq!(Date::new(2023, 2, 29), Err(DateError::InvalidDay { month: 2, day: 29 }));
+        assert_eq!(Date::new(1900, 2, 29), Err(DateError::InvalidDay { month: 2, day: 29 }));
+        assert_eq!(Date::new(2024, 1, 0), Err(DateError::InvalidDay { month: 1, day: 0 }));
+        assert!(Date::new(2000, 2, 29).is_ok());
+    }
+
+    #[test]
+    fn known_differences() {
+        assert_eq!(days_between(&date(1970, 1, 1), &date(2000, 1, 1)), 10957);
+        assert_eq!(days_between(&date(1900, 1, 1), &date(2000, 1, 1)), 36524);
+        // From the first powered flight to the first moon landing.
+        assert_eq!(days_between(&date(1903, 12, 17), &date(1969, 7, 20)), 23957);
+        // From the construction to the fall of the Berlin Wall.
+        assert_eq!(days_between(&date(1961, 8, 13), &date(1989, 11, 9)), 10315);
+        assert_eq!(days_between(&date(2024, 2, 28), &date(2024, 3, 1)), 2);
+        assert_eq!(days_between(&date(2023, 2, 28), &date(2023, 3, 1)), 1);
+        assert_eq!(days_between(&date(1970, 1, 1), &date(1, 1, 1)), -719162);
+    }
+
+    #[test]
+    fn reversed_order_is_negative() {
+        let a = date(2020, 5, 17);
+        let b = date(2021, 5, 17);
+        assert_eq!(days_between(&a, &b), 365);
+        assert_eq!(days_between(&b, &a), -365);
+        assert_eq!(days_between(&a, &a), 0);
+    }
+
+    #[test]
+    fn add_days() {
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 2, 28).add_days(1), date(2023, 3, 1));
+        assert_eq!(date(1999, 12, 31).add_days(1), date(2000, 1, 1));
+        assert_eq!(date(2000, 1, 1).add_days(-1), date(1999, 12, 31));
+        assert_eq!(date(1970, 1, 1).add_days(10957), date(2000, 1, 1));
+        assert_eq!(date(1, 1, 1).add_days(-1), date(0, 12, 31));
+    }
+
+    #[test]
+    fn round_trip() {
+        let start = date(1600, 1, 1);
+        let mut current = start;
+        // Walk day by day through more than 400 years, checking every date on the way.
+        for offset in 1..150_000 {
+            let next = current.add_days(1);
+            assert_eq!(Date::new(next.year, next.month, next.day), Ok(next));
+            assert_eq!(days_between(&current, &next), 1);
+            assert_eq!(days_between(&start, &next), offset);
+            assert_eq!(start.add_days(offset), next);
+            current = next;
+        }
+
+        for days in [-1_000_000, -12345, 0, 777, 3_000_000] {
+            let other = start.add_days(days);
+            assert_eq!(days_between(&start, &other), days);
+            assert_eq!(other.add_days(-days), start);
+        }
+    }
+}
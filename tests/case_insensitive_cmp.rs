@@ -4,19 +4,155 @@
 //! two (ASCII) string slices in a case insensitive way, without performing any reallocations
 //! and without modifying the original strings.
 
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
 struct CaseInsensitive<'a>(&'a str);
 
+/// Compares two strings byte by byte after ASCII case folding, stopping at the first difference.
+fn cmp_ignore_ascii_case(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    for (x, y) in a.iter().zip(b.iter()) {
+        match x.to_ascii_lowercase().cmp(&y.to_ascii_lowercase()) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+
+    a.len().cmp(&b.len())
+}
+
+/// The ordering counterpart of `str::eq_ignore_ascii_case`: returns `Ordering::Equal` exactly
+/// when `a.eq_ignore_ascii_case(b)` holds.
+fn eq_ignore_ascii_case_ord(a: &str, b: &str) -> Ordering {
+    cmp_ignore_ascii_case(a, b)
+}
+
 impl <'a>PartialEq for CaseInsensitive<'a> {
     fn eq(&self, other: &Self) -> bool {
-        self.0.bytes().map(|b| b.to_ascii_lowercase()).cmp(other.0.bytes().map(|b| b.to_ascii_lowercase())).is_eq()
+        self.0.eq_ignore_ascii_case(other.0)
     }
 }
 
+impl<'a> Eq for CaseInsensitive<'a> {}
+
 impl <'a>PartialOrd for CaseInsensitive<'a> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(
-            self.0.bytes().map(|b| b.to_ascii_lowercase()).cmp(other.0.bytes().map(|b| b.to_ascii_lowercase()))
-        )
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for CaseInsensitive<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ignore_ascii_case(self.0, other.0)
+    }
+}
+
+/// An owned `CaseInsensitive`, e.g. for use as a map key.
+#[derive(Debug, Clone)]
+struct CaseInsensitiveString(String);
+
+impl PartialEq for CaseInsensitiveString {
+    fn eq(&self, other: &Self) -> bool {
+        CaseInsensitive(&self.0) == CaseInsensitive(&other.0)
+    }
+}
+
+impl Eq for CaseInsensitiveString {}
+
+impl PartialOrd for CaseInsensitiveString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CaseInsensitiveString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        CaseInsensitive(&self.0).cmp(&CaseInsensitive(&other.0))
+    }
+}
+
+/// The borrowed form of `CaseInsensitiveString`, so that maps keyed by it can be queried with a
+/// `&str` without allocating.
+#[repr(transparent)]
+struct CaseInsensitiveStr(str);
+
+impl CaseInsensitiveStr {
+    fn new(s: &str) -> &CaseInsensitiveStr {
+        // SAFETY: `CaseInsensitiveStr` is a `repr(transparent)` wrapper around `str`, so both
+        // references have the same layout.
+        unsafe { &*(s as *const str as *const CaseInsensitiveStr) }
+    }
+}
+
+impl PartialEq for CaseInsensitiveStr {
+    fn eq(&self, other: &Self) -> bool {
+        CaseInsensitive(&self.0) == CaseInsensitive(&other.0)
+    }
+}
+
+impl Eq for CaseInsensitiveStr {}
+
+impl PartialOrd for CaseInsensitiveStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CaseInsensitiveStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        CaseInsensitive(&self.0).cmp(&CaseInsensitive(&other.0))
+    }
+}
+
+impl Borrow<CaseInsensitiveStr> for CaseInsensitiveString {
+    fn borrow(&self) -> &CaseInsensitiveStr {
+        CaseInsensitiveStr::new(&self.0)
+    }
+}
+
+/// A map with case insensitive keys, iterated in case insensitive order. A key keeps the spelling
+/// it was first inserted with.
+#[derive(Debug, Default)]
+struct CaseInsensitiveMap<V> {
+    entries: BTreeMap<CaseInsensitiveString, V>,
+}
+
+impl<V> CaseInsensitiveMap<V> {
+    /// Inserts `value` under `key`, returning the value previously stored under any spelling of
+    /// `key`.
+    fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        self.entries.insert(CaseInsensitiveString(key.to_string()), value)
+    }
+
+    fn get(&self, key: &str) -> Option<&V> {
+        self.entries.get(CaseInsensitiveStr::new(key))
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.entries.iter().map(|(key, value)| (key.0.as_str(), value))
+    }
+}
+
+/// Like `CaseInsensitive`, but ignores leading and trailing ASCII whitespace, so that `" Foo "`
+/// equals `"foo"`.
+struct CaseInsensitiveTrimmed<'a>(&'a str);
+
+impl<'a> PartialEq for CaseInsensitiveTrimmed<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        CaseInsensitive(self.0.trim_ascii()) == CaseInsensitive(other.0.trim_ascii())
+    }
+}
+
+impl<'a> PartialOrd for CaseInsensitiveTrimmed<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        CaseInsensitive(self.0.trim_ascii()).partial_cmp(&CaseInsensitive(other.0.trim_ascii()))
     }
 }
 
@@ -24,7 +160,11 @@ impl <'a>PartialOrd for CaseInsensitive<'a> {
 /// Below you can find a set of unit tests.
 #[cfg(test)]
 mod tests {
-    use crate::CaseInsensitive;
+    use crate::{
+        cmp_ignore_ascii_case, eq_ignore_ascii_case_ord, CaseInsensitive, CaseInsensitiveMap,
+        CaseInsensitiveTrimmed,
+    };
+    use proptest::prelude::*;
 
     #[test]
     fn case_insensitive_same() {
@@ -54,4 +194,101 @@ mod tests {
         assert!(CaseInsensitive("PWEaszDsx") > CaseInsensitive("PWEasUDsx"));
         assert!(CaseInsensitive("PWEasZDsx") > CaseInsensitive("PWEasuDsx"));
     }
+
+    #[test]
+    fn case_insensitive_stops_at_first_difference() {
+        assert_eq!(cmp_ignore_ascii_case("aXXXXXXX", "bxxxxxxx"), std::cmp::Ordering::Less);
+        assert_eq!(cmp_ignore_ascii_case("abc", "ABCD"), std::cmp::Ordering::Less);
+        assert_eq!(cmp_ignore_ascii_case("ABC", "abc"), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn case_insensitive_trimmed() {
+        assert!(CaseInsensitiveTrimmed(" Foo ") == CaseInsensitiveTrimmed("foo"));
+        assert!(CaseInsensitiveTrimmed("\tFOO\r\n") == CaseInsensitiveTrimmed("  foo"));
+        assert!(CaseInsensitiveTrimmed("   ") == CaseInsensitiveTrimmed(""));
+        assert!(CaseInsensitiveTrimmed(" a b ") != CaseInsensitiveTrimmed("ab"));
+        assert!(CaseInsensitiveTrimmed("  a") < CaseInsensitiveTrimmed("B"));
+        assert!(CaseInsensitiveTrimmed("zz ") > CaseInsensitiveTrimmed(" ZA"));
+    }
+
+    #[test]
+    fn case_insensitive_does_not_trim() {
+        assert!(CaseInsensitive(" Foo ") != CaseInsensitive("foo"));
+        assert!(CaseInsensitive(" z") < CaseInsensitive("a"));
+    }
+
+    #[test]
+    fn consistent_with_std_eq_ignore_ascii_case() {
+        let words = [
+            "", "a", "A", "ab", "AB", "aB", "b", "abc", "ABD", "Straße", "STRAßE", "STRASSE", "é",
+            "É", "[", "{", "@", "`", "Foo Bar", "fOO bAR", "foo_bar",
+        ];
+        for a in words {
+            for b in words {
+                let expected = a.eq_ignore_ascii_case(b);
+                assert_eq!(eq_ignore_ascii_case_ord(a, b).is_eq(), expected, "{a:?} {b:?}");
+                assert_eq!(CaseInsensitive(a) == CaseInsensitive(b), expected, "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn case_insensitive_ord() {
+        let mut words = [
+            CaseInsensitive("b"),
+            CaseInsensitive("C"),
+            CaseInsensitive("A"),
+            CaseInsensitive("aa"),
+        ];
+        words.sort();
+        assert_eq!(words.iter().map(|w| w.0).collect::<Vec<_>>(), ["A", "aa", "b", "C"]);
+        assert_eq!(CaseInsensitive("x").max(CaseInsensitive("Y")).0, "Y");
+    }
+
+    #[test]
+    fn case_insensitive_map_order() {
+        let mut map = CaseInsensitiveMap::default();
+        map.insert("B", 2);
+        map.insert("a", 1);
+        map.insert("C", 3);
+        assert_eq!(map.iter().collect::<Vec<_>>(), [("a", &1), ("B", &2), ("C", &3)]);
+    }
+
+    #[test]
+    fn case_insensitive_map_lookup() {
+        let mut map = CaseInsensitiveMap::default();
+        assert_eq!(map.insert("Content-Type", "text/plain"), None);
+        assert_eq!(map.get("content-type"), Some(&"text/plain"));
+        assert_eq!(map.get("CONTENT-TYPE"), Some(&"text/plain"));
+        assert_eq!(map.get("content-length"), None);
+
+        assert_eq!(map.insert("CONTENT-TYPE", "text/html"), Some("text/plain"));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.iter().collect::<Vec<_>>(), [("Content-Type", &"text/html")]);
+    }
+
+    proptest! {
+        #[test]
+        fn case_insensitive_matches_lowercased_cmp(a in "[a-cA-C0-9]{0,8}", b in "[a-cA-C0-9]{0,8}") {
+            let expected = a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase());
+            prop_assert_eq!(cmp_ignore_ascii_case(&a, &b), expected);
+            prop_assert_eq!(CaseInsensitive(&a).partial_cmp(&CaseInsensitive(&b)), Some(expected));
+            prop_assert_eq!(CaseInsensitive(&a) == CaseInsensitive(&b), expected.is_eq());
+        }
+
+        #[test]
+        fn case_insensitive_matches_for_any_ascii(a in "[ -~]{0,32}", b in "[ -~]{0,32}") {
+            let expected = a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase());
+            prop_assert_eq!(CaseInsensitive(&a).partial_cmp(&CaseInsensitive(&b)), Some(expected));
+        }
+
+        #[test]
+        fn ord_matches_std_eq_ignore_ascii_case(a in "[a-cA-CéÉ ]{0,6}", b in "[a-cA-CéÉ ]{0,6}") {
+            let expected = a.eq_ignore_ascii_case(&b);
+            prop_assert_eq!(eq_ignore_ascii_case_ord(&a, &b).is_eq(), expected);
+            prop_assert_eq!(CaseInsensitive(&a) == CaseInsensitive(&b), expected);
+            prop_assert_eq!(eq_ignore_ascii_case_ord(&a, &b), eq_ignore_ascii_case_ord(&b, &a).reverse());
+        }
+    }
 }
\ No newline at end of file
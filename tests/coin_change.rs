@@ -0,0 +1,110 @@
+//! Run this file with `cargo test --test coin_change`.
+
+//! TODO: Solve the two classic variants of the coin change problem. Every coin denomination in
+//! `coins` can be used any number of times.
+//!
+//! - `min_coins`: the smallest number of coins that add up to `amount`, or `None` if `amount`
+//!   cannot be paid with these coins.
+//! - `coin_combinations`: the number of distinct ways to pay `amount`, where the order of the coins
+//!   does not matter (`1 + 2` and `2 + 1` are the same combination).
+//!
+//! An amount of `0` is paid with zero coins, in exactly one way. Coins with a value of `0` are
+//! useless and should be ignored.
+//!
+//! Hint: both problems can be solved with dynamic programming over all amounts from `0` to
+//! `amount`. For `coin_combinations`, process one denomination at a time, so that every
+//! combination is only counted in one order.
+
+fn min_coins(coins: &[u64], amount: u64) -> Option<usize> {
+    let amount = amount as usize;
+
+    // `fewest[a]` is the smallest number of coins that add up to `a`, if there is any.
+    let mut fewest: Vec<Option<usize>> = vec![None; amount + 1];
+    fewest[0] = Some(0);
+
+    for a in 1..=amount {
+        fewest[a] = coins
+            .iter()
+            .filter(|&&coin| coin > 0 && coin as usize <= a)
+            .filter_map(|&coin| fewest[a - coin as usize])
+            .min()
+            .map(|count| count + 1);
+    }
+
+    fewest[amount]
+}
+
+fn coin_combinations(coins: &[u64], amount: u64) -> u64 {
+    let amount = amount as usize;
+
+    // `ways[a]` is the number of ways to pay `a` with the denominations processed so far.
+    let mut ways = vec![0u64; amount + 1];
+    ways[0] = 1;
+
+    let mut denominations: Vec<usize> = coins.iter().map(|&coin| coin as usize).collect();
+    denominations.sort_unstable();
+    denominations.dedup();
+
+    for coin in denominations.into_iter().filter(|&coin| coin > 0) {
+        for a in coin..=amount {
+            ways[a] += ways[a - coin];
+        }
+    }
+
+    ways[amount]
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{coin_combinations, min_coins};
+
+    #[test]
+    fn zero_amount() {
+        assert_eq!(min_coins(&[1, 2, 5], 0), Some(0));
+        assert_eq!(min_coins(&[], 0), Some(0));
+        assert_eq!(coin_combinations(&[1, 2, 5], 0), 1);
+        assert_eq!(coin_combinations(&[], 0), 1);
+    }
+
+    #[test]
+    fn standard_denominations() {
+        let coins = [1, 2, 5, 10, 20, 50];
+        assert_eq!(min_coins(&coins, 11), Some(2));
+        assert_eq!(min_coins(&coins, 99), Some(6));
+        assert_eq!(min_coins(&coins, 1), Some(1));
+        assert_eq!(coin_combinations(&[1, 2, 5], 5), 4);
+        assert_eq!(coin_combinations(&[1, 5, 10, 25], 100), 242);
+    }
+
+    #[test]
+    fn greedy_is_not_enough() {
+        // Greedily taking the largest coin would use 4 + 1 + 1.
+        assert_eq!(min_coins(&[1, 3, 4], 6), Some(2));
+        assert_eq!(min_coins(&[25, 10, 1], 30), Some(3));
+    }
+
+    #[test]
+    fn impossible_amount() {
+        assert_eq!(min_coins(&[2], 3), None);
+        assert_eq!(min_coins(&[5, 10], 7), None);
+        assert_eq!(min_coins(&[], 1), None);
+        assert_eq!(coin_combinations(&[2], 3), 0);
+        assert_eq!(coin_combinations(&[], 1), 0);
+    }
+
+    #[test]
+    fn zero_and_duplicate_coins() {
+        assert_eq!(min_coins(&[0, 3], 9), Some(3));
+        assert_eq!(min_coins(&[0], 1), None);
+        assert_eq!(coin_combinations(&[0, 1, 2], 4), 3);
+        assert_eq!(coin_combinations(&[2, 1, 2], 4), 3);
+    }
+
+    #[test]
+    fn order_does_not_matter() {
+        assert_eq!(coin_combinations(&[1, 2], 3), 2);
+        assert_eq!(coin_combinations(&[2, 1], 3), 2);
+        assert_eq!(coin_combinations(&[3, 5, 7], 15), 3);
+    }
+}
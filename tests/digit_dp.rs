@@ -0,0 +1,132 @@
+//! Run this file with `cargo test --test digit_dp`.
+
+//! TODO: Count the integers in a range whose digits satisfy some property.
+//!
+//! - `count_with_property`: counts the integers in `[lo, hi]` for which `property` returns `true`,
+//!   by simply checking every one of them.
+//! - `count_digit_sum_equal`: counts the integers in `[lo, hi]` whose digit sum equals `target`.
+//!   Checking every number is far too slow for large ranges, so use the
+//!   [digit DP](https://codeforces.com/blog/entry/53960) technique instead.
+//!
+//! An empty range (`lo > hi`) contains no integers.
+//!
+//! Hint: count the matching numbers in `[0, n]` and subtract `[0, lo - 1]` from `[0, hi]`. To count
+//! up to `n`, walk over the digits of `n` from the most significant one. At every position, each
+//! digit smaller than the one in `n` frees all remaining positions, and the number of ways to fill
+//! `k` free digits with a given sum can be precomputed.
+
+/// The largest possible digit sum of a `u64` (twenty digits of 9).
+const MAX_DIGIT_SUM: usize = 20 * 9;
+
+fn digit_sum(mut n: u64) -> u32 {
+    let mut sum = 0;
+    while n > 0 {
+        sum += (n % 10) as u32;
+        n /= 10;
+    }
+    sum
+}
+
+fn count_with_property(lo: u64, hi: u64, property: impl Fn(u64) -> bool) -> u64 {
+    if lo > hi {
+        return 0;
+    }
+    (lo..=hi).filter(|n| property(*n)).count() as u64
+}
+
+/// Counts the integers in `[0, n]` whose digit sum equals `target`.
+fn count_up_to(n: u64, target: usize) -> u64 {
+    let digits: Vec<usize> = n.to_string().bytes().map(|b| (b - b'0') as usize).collect();
+
+    // `ways[k][s]` is the number of ways to fill `k` free digits so that they sum to `s`.
+    let mut ways = vec![vec![0u64; MAX_DIGIT_SUM + 1]; digits.len() + 1];
+    ways[0][0] = 1;
+    for k in 1..=digits.len() {
+        for s in 0..=MAX_DIGIT_SUM {
+            ways[k][s] = (0..=9.min(s)).map(|d| ways[k - 1][s - d]).sum();
+        }
+    }
+
+    let mut count = 0;
+    let mut prefix_sum = 0;
+    for (position, &digit) in digits.iter().enumerate() {
+        let free = digits.len() - position - 1;
+        for smaller in 0..digit {
+            if let Some(rest) = target.checked_sub(prefix_sum + smaller) {
+                count += ways[free][rest];
+            }
+        }
+        prefix_sum += digit;
+    }
+
+    // `n` itself is not counted by the loop above.
+    if prefix_sum == target {
+        count += 1;
+    }
+
+    count
+}
+
+fn count_digit_sum_equal(lo: u64, hi: u64, target: u32) -> u64 {
+    let target = target as usize;
+    if lo > hi || target > MAX_DIGIT_SUM {
+        return 0;
+    }
+
+    let below_lo = match lo.checked_sub(1) {
+        Some(n) => count_up_to(n, target),
+        None => 0,
+    };
+    count_up_to(hi, target) - below_lo
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{count_digit_sum_equal, count_with_property, digit_sum};
+
+    #[test]
+    fn brute_force() {
+        assert_eq!(count_with_property(1, 100, |n| n % 7 == 0), 14);
+        assert_eq!(count_with_property(0, 0, |_| true), 1);
+        assert_eq!(count_with_property(5, 4, |_| true), 0);
+        assert_eq!(count_with_property(10, 99, |n| n / 10 == n % 10), 9);
+    }
+
+    #[test]
+    fn digit_sum_small_ranges() {
+        assert_eq!(count_digit_sum_equal(0, 9, 5), 1);
+        assert_eq!(count_digit_sum_equal(0, 100, 1), 3);
+        assert_eq!(count_digit_sum_equal(10, 99, 9), 9);
+        assert_eq!(count_digit_sum_equal(0, 0, 0), 1);
+        assert_eq!(count_digit_sum_equal(1, 1000, 0), 0);
+    }
+
+    #[test]
+    fn empty_or_impossible() {
+        assert_eq!(count_digit_sum_equal(10, 9, 1), 0);
+        assert_eq!(count_digit_sum_equal(0, u64::MAX, 1000), 0);
+        assert_eq!(count_digit_sum_equal(0, 99, 19), 0);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        for (lo, hi) in [(0, 1000), (1, 999), (123, 4567), (990, 1010), (5000, 5000)] {
+            for target in 0..30 {
+                assert_eq!(
+                    count_digit_sum_equal(lo, hi, target),
+                    count_with_property(lo, hi, |n| digit_sum(n) == target),
+                    "range [{lo}, {hi}], target {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn large_range() {
+        // Only 999...9 (eighteen nines) sums to 162, and only the powers of ten sum to 1.
+        assert_eq!(count_digit_sum_equal(0, 999_999_999_999_999_999, 162), 1);
+        assert_eq!(count_digit_sum_equal(0, 999_999_999_999_999_999, 1), 18);
+        assert_eq!(count_digit_sum_equal(u64::MAX, u64::MAX, digit_sum(u64::MAX)), 1);
+    }
+}
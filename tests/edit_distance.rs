@@ -0,0 +1,129 @@
+//! Run this file with `cargo test --test edit_distance`.
+
+//! TODO: Compute the edit distance between two strings, compared character by character.
+//!
+//! - `levenshtein`: the [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance),
+//!   i.e. the smallest number of inserted, deleted or substituted characters that turn `a` into
+//!   `b`.
+//! - `weighted_edit_distance`: the same, but every operation has its own cost, so the result is
+//!   the cheapest total cost of turning `a` into `b`. With all costs set to `1.0` this is the
+//!   Levenshtein distance. A substitution that costs more than a deletion and an insertion
+//!   together is never worth doing.
+//!
+//! Hint: let `d[i][j]` be the distance between the first `i` characters of `a` and the first `j`
+//! characters of `b`. It can be computed from `d[i - 1][j]` (deleting the `i`-th character of
+//! `a`), `d[i][j - 1]` (inserting the `j`-th character of `b`) and `d[i - 1][j - 1]` (keeping or
+//! substituting the character).
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // Only the previous row of the table is needed to compute the next one.
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let substitution = previous[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            current[j] = substitution.min(previous[j] + 1).min(current[j - 1] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+fn weighted_edit_distance(a: &str, b: &str, insert: f64, delete: f64, substitute: f64) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous: Vec<f64> = (0..=b.len()).map(|j| j as f64 * insert).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i as f64 * delete; b.len() + 1];
+        for j in 1..=b.len() {
+            let substitution = if a[i - 1] == b[j - 1] {
+                previous[j - 1]
+            } else {
+                previous[j - 1] + substitute
+            };
+            current[j] = substitution
+                .min(previous[j] + delete)
+                .min(current[j - 1] + insert);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{levenshtein, weighted_edit_distance};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn levenshtein_examples() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("rust", "rust"), 0);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("", ""), 0);
+    }
+
+    #[test]
+    fn levenshtein_unicode() {
+        assert_eq!(levenshtein("čaj", "caj"), 1);
+        assert_eq!(levenshtein("🦀", "🦀🦀"), 1);
+    }
+
+    #[test]
+    fn unit_weights_match_levenshtein() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("flaw", "lawn"),
+            ("intention", "execution"),
+            ("", "abc"),
+            ("abc", ""),
+            ("same", "same"),
+            ("ab", "ba"),
+        ];
+        for (a, b) in pairs {
+            assert_close(
+                weighted_edit_distance(a, b, 1.0, 1.0, 1.0),
+                levenshtein(a, b) as f64,
+            );
+        }
+    }
+
+    #[test]
+    fn expensive_substitution() {
+        // With cheap insertions and deletions, replacing a character by deleting and inserting
+        // beats substituting it.
+        assert_close(weighted_edit_distance("a", "b", 0.75, 0.75, 2.0), 1.5);
+        assert_close(weighted_edit_distance("kitten", "sitting", 0.75, 0.75, 2.0), 3.75);
+        // With unit insertions and deletions, both alignments cost the same.
+        assert_close(weighted_edit_distance("kitten", "sitting", 1.0, 1.0, 2.0), 5.0);
+    }
+
+    #[test]
+    fn cheap_substitution() {
+        assert_close(weighted_edit_distance("abc", "xyz", 1.0, 1.0, 0.5), 1.5);
+        // The length difference still has to be inserted.
+        assert_close(weighted_edit_distance("ab", "xyz", 1.0, 1.0, 0.5), 2.0);
+    }
+
+    #[test]
+    fn asymmetric_costs() {
+        assert_close(weighted_edit_distance("", "abc", 2.0, 1.0, 1.0), 6.0);
+        assert_close(weighted_edit_distance("abc", "", 2.0, 1.0, 1.0), 3.0);
+        assert_close(weighted_edit_distance("abcd", "b", 5.0, 0.5, 5.0), 1.5);
+    }
+}
@@ -0,0 +1,71 @@
+//! Run this file with `cargo test --test etl`.
+
+//! TODO: Implement a function called `transform`, which converts Scrabble scores from the legacy
+//! format to the new one.
+//! The legacy format maps a point value to the list of letters worth that many points, e.g.
+//! `1 => ['A', 'E', 'I', ...]`. The new format maps every letter to its point value instead,
+//! e.g. `'a' => 1`. Letters in the new format are always lowercase.
+
+use std::collections::HashMap;
+
+fn transform(legacy: &HashMap<u32, Vec<char>>) -> HashMap<char, u32> {
+    legacy
+        .iter()
+        .flat_map(|(points, letters)| {
+            letters
+                .iter()
+                .map(move |letter| (letter.to_ascii_lowercase(), *points))
+        })
+        .collect()
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::transform;
+    use std::collections::HashMap;
+
+    #[test]
+    fn transform_empty() {
+        assert!(transform(&HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn transform_single_letter() {
+        let legacy = HashMap::from([(1, vec!['a'])]);
+        assert_eq!(transform(&legacy), HashMap::from([('a', 1)]));
+    }
+
+    #[test]
+    fn transform_lowercases_letters() {
+        let legacy = HashMap::from([(1, vec!['A', 'E']), (2, vec!['D', 'g'])]);
+        assert_eq!(
+            transform(&legacy),
+            HashMap::from([('a', 1), ('e', 1), ('d', 2), ('g', 2)])
+        );
+    }
+
+    #[test]
+    fn transform_standard_scrabble() {
+        let legacy = HashMap::from([
+            (1, vec!['A', 'E', 'I', 'O', 'U', 'L', 'N', 'R', 'S', 'T']),
+            (2, vec!['D', 'G']),
+            (3, vec!['B', 'C', 'M', 'P']),
+            (4, vec!['F', 'H', 'V', 'W', 'Y']),
+            (5, vec!['K']),
+            (8, vec!['J', 'X']),
+            (10, vec!['Q', 'Z']),
+        ]);
+
+        let expected = HashMap::from([
+            ('a', 1), ('b', 3), ('c', 3), ('d', 2), ('e', 1), ('f', 4), ('g', 2),
+            ('h', 4), ('i', 1), ('j', 8), ('k', 5), ('l', 1), ('m', 3), ('n', 1),
+            ('o', 1), ('p', 3), ('q', 10), ('r', 1), ('s', 1), ('t', 1), ('u', 1),
+            ('v', 4), ('w', 4), ('x', 8), ('y', 4), ('z', 10),
+        ]);
+
+        let transformed = transform(&legacy);
+        assert_eq!(transformed.len(), 26);
+        assert_eq!(transformed, expected);
+    }
+}
@@ -0,0 +1,306 @@
+//! Run this file with `cargo test --test expression_tree`.
+
+//! TODO: Build an expression tree (abstract syntax tree) from a tokenized arithmetic expression and
+//! evaluate it.
+//!
+//! The tokens are the same as in the `simple_calculator` exercise: numbers, the binary operators
+//! `+`, `-`, `*` and `/`, unary minus and parentheses. `*` and `/` bind tighter than `+` and `-`,
+//! and operators of the same precedence are applied from left to right.
+//!
+//! - `parse`: builds an `Expr` from the tokens. There is no node for unary minus, represent `-x`
+//!   as `0 - x` instead.
+//! - `evaluate`: computes the value of an `Expr`, dividing by zero is an error.
+//!
+//! Hint: a [recursive descent parser](https://en.wikipedia.org/wiki/Recursive_descent_parser) with
+//! one function per precedence level is a natural fit: an expression is a sum of terms, a term is
+//! a product of factors, and a factor is a number, a negated factor or a parenthesized expression.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+enum ParseError {
+    UnknownToken(char),
+    InvalidNumber(String),
+    /// The token at `location` (an index into the tokens) cannot appear there.
+    UnexpectedToken { location: usize },
+    UnexpectedEnd,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, PartialEq)]
+enum EvalError {
+    DivisionByZero,
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for EvalError {}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Op(Operator),
+    LeftParen,
+    RightParen,
+}
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Number(f64),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = expr.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            let mut number = String::new();
+            while let Some(&d) = chars.peek() {
+                if !(d.is_ascii_digit() || d == '.') {
+                    break;
+                }
+                number.push(d);
+                chars.next();
+            }
+
+            let value = number
+                .parse()
+                .map_err(|_| ParseError::InvalidNumber(number.clone()))?;
+            tokens.push(Token::Number(value));
+            continue;
+        }
+
+        // A minus is unary when it cannot refer to a left operand.
+        let follows_operand = matches!(tokens.last(), Some(Token::Number(_) | Token::RightParen));
+        let token = match c {
+            '+' => Token::Op(Operator::Add),
+            '-' if follows_operand => Token::Op(Operator::Sub),
+            '-' => Token::Op(Operator::Neg),
+            '*' => Token::Op(Operator::Mul),
+            '/' => Token::Op(Operator::Div),
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            _ => return Err(ParseError::UnknownToken(c)),
+        };
+        tokens.push(token);
+        chars.next();
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent parser over a slice of tokens.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<Token, ParseError> {
+        let token = self.peek().ok_or(ParseError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    /// expression := term (("+" | "-") term)*
+    fn expression(&mut self) -> Result<Expr, ParseError> {
+        let mut left = self.term()?;
+        while let Some(Token::Op(op @ (Operator::Add | Operator::Sub))) = self.peek() {
+            self.position += 1;
+            let right = Box::new(self.term()?);
+            left = match op {
+                Operator::Add => Expr::Add(Box::new(left), right),
+                _ => Expr::Sub(Box::new(left), right),
+            };
+        }
+        Ok(left)
+    }
+
+    /// term := factor (("*" | "/") factor)*
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let mut left = self.factor()?;
+        while let Some(Token::Op(op @ (Operator::Mul | Operator::Div))) = self.peek() {
+            self.position += 1;
+            let right = Box::new(self.factor()?);
+            left = match op {
+                Operator::Mul => Expr::Mul(Box::new(left), right),
+                _ => Expr::Div(Box::new(left), right),
+            };
+        }
+        Ok(left)
+    }
+
+    /// factor := number | "-" factor | "(" expression ")"
+    fn factor(&mut self) -> Result<Expr, ParseError> {
+        let location = self.position;
+        match self.next()? {
+            Token::Number(value) => Ok(Expr::Number(value)),
+            Token::Op(Operator::Neg) => {
+                let operand = self.factor()?;
+                Ok(Expr::Sub(Box::new(Expr::Number(0.0)), Box::new(operand)))
+            }
+            Token::LeftParen => {
+                let inner = self.expression()?;
+                let location = self.position;
+                match self.next()? {
+                    Token::RightParen => Ok(inner),
+                    _ => Err(ParseError::UnexpectedToken { location }),
+                }
+            }
+            _ => Err(ParseError::UnexpectedToken { location }),
+        }
+    }
+}
+
+fn parse(tokens: &[Token]) -> Result<Expr, ParseError> {
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let expr = parser.expression()?;
+
+    if parser.position < tokens.len() {
+        return Err(ParseError::UnexpectedToken {
+            location: parser.position,
+        });
+    }
+
+    Ok(expr)
+}
+
+fn evaluate(expr: &Expr) -> Result<f64, EvalError> {
+    Ok(match expr {
+        Expr::Number(value) => *value,
+        Expr::Add(left, right) => evaluate(left)? + evaluate(right)?,
+        Expr::Sub(left, right) => evaluate(left)? - evaluate(right)?,
+        Expr::Mul(left, right) => evaluate(left)? * evaluate(right)?,
+        Expr::Div(left, right) => {
+            let divisor = evaluate(right)?;
+            if divisor == 0.0 {
+                return Err(EvalError::DivisionByZero);
+            }
+            evaluate(left)? / divisor
+        }
+    })
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{evaluate, parse, tokenize, EvalError, Expr, ParseError};
+
+    fn num(value: f64) -> Box<Expr> {
+        Box::new(Expr::Number(value))
+    }
+
+    fn parse_str(expr: &str) -> Result<Expr, ParseError> {
+        parse(&tokenize(expr)?)
+    }
+
+    fn eval_str(expr: &str) -> Result<f64, EvalError> {
+        evaluate(&parse_str(expr).unwrap())
+    }
+
+    #[test]
+    fn parse_number() {
+        assert_eq!(parse_str("42"), Ok(Expr::Number(42.0)));
+        assert_eq!(parse_str("(((1.5)))"), Ok(Expr::Number(1.5)));
+    }
+
+    #[test]
+    fn parse_fully_parenthesized() {
+        assert_eq!(
+            parse_str("((1 + 2) * (3 - 4))"),
+            Ok(Expr::Mul(
+                Box::new(Expr::Add(num(1.0), num(2.0))),
+                Box::new(Expr::Sub(num(3.0), num(4.0)))
+            ))
+        );
+        assert_eq!(
+            parse_str("(8 / (4 / 2))"),
+            Ok(Expr::Div(num(8.0), Box::new(Expr::Div(num(4.0), num(2.0)))))
+        );
+    }
+
+    #[test]
+    fn parse_precedence_and_associativity() {
+        assert_eq!(
+            parse_str("1 + 2 * 3"),
+            Ok(Expr::Add(num(1.0), Box::new(Expr::Mul(num(2.0), num(3.0)))))
+        );
+        assert_eq!(
+            parse_str("8 - 4 - 2"),
+            Ok(Expr::Sub(Box::new(Expr::Sub(num(8.0), num(4.0))), num(2.0)))
+        );
+    }
+
+    #[test]
+    fn parse_unary_minus() {
+        assert_eq!(parse_str("-3"), Ok(Expr::Sub(num(0.0), num(3.0))));
+        assert_eq!(eval_str("-(1 + 2) * 2"), Ok(-6.0));
+        assert_eq!(eval_str("2 * -3"), Ok(-6.0));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse_str(""), Err(ParseError::UnexpectedEnd));
+        assert_eq!(parse_str("1 +"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(parse_str("(1 + 2"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(parse_str("1 + 2)"), Err(ParseError::UnexpectedToken { location: 3 }));
+        assert_eq!(parse_str("1 2"), Err(ParseError::UnexpectedToken { location: 1 }));
+        assert_eq!(parse_str("* 2"), Err(ParseError::UnexpectedToken { location: 0 }));
+        assert_eq!(parse_str("(1 2)"), Err(ParseError::UnexpectedToken { location: 2 }));
+        assert_eq!(parse_str("2 ^ 3"), Err(ParseError::UnknownToken('^')));
+        assert_eq!(parse_str("1.2.3"), Err(ParseError::InvalidNumber("1.2.3".to_string())));
+    }
+
+    #[test]
+    fn evaluate_expressions() {
+        assert_eq!(eval_str("((1 + 2) * (3 - 4))"), Ok(-3.0));
+        assert_eq!(eval_str("2 * (3 + 4) / 7"), Ok(2.0));
+        assert_eq!(eval_str("10 / 4"), Ok(2.5));
+        assert_eq!(eval_str("8 - 4 - 2"), Ok(2.0));
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(eval_str("1 / 0"), Err(EvalError::DivisionByZero));
+        assert_eq!(eval_str("(5 / (2 - 2))"), Err(EvalError::DivisionByZero));
+        assert_eq!(eval_str("0 / 5"), Ok(0.0));
+    }
+}
@@ -15,21 +15,38 @@ impl Default for Fibonacci {
     }
 }
 
+impl Fibonacci {
+    /// Returns the `n`-th Fibonacci number without advancing the iterator.
+    fn term(&mut self, n: usize) -> Option<u64> {
+        while self.fib_list.len() <= n {
+            let len = self.fib_list.len();
+            // Ends the sequence instead of overflowing once the numbers no longer fit into a `u64`.
+            let fib_val = self.fib_list[len - 1].checked_add(self.fib_list[len - 2])?;
+            self.fib_list.push(fib_val);
+        }
+        Some(self.fib_list[n])
+    }
+
+    /// Yields the Fibonacci numbers strictly less than `limit`.
+    fn below(limit: u64) -> impl Iterator<Item = u64> {
+        Self::default().take_while(move |&term| term < limit)
+    }
+}
+
 impl Iterator for Fibonacci {
     type Item = u64;
 
     fn next(&mut self) -> Option<Self::Item> {
-       let result = self.nth(self.n)?;
+       let result = self.term(self.n)?;
        self.n += 1;
        Some(result)
     }
 
+    /// Skips `n` numbers and returns the next one, advancing the iterator past it like
+    /// `Iterator::nth` does.
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if n < self.fib_list.len() { return Some(self.fib_list[n]) }
-
-        let fib_val = self.nth(n - 1)? + self.nth(n - 2)?;
-        self.fib_list.push(fib_val);
-        Some(fib_val)
+        self.n = self.n.saturating_add(n);
+        self.next()
     }
 }
 
@@ -61,4 +78,47 @@ mod tests {
     fn fibonacci_sixty() {
         assert_eq!(Fibonacci::default().nth(59), Some(956722026041));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn fibonacci_next_after_nth() {
+        let mut fib = Fibonacci::default();
+        assert_eq!(fib.nth(5), Some(5));
+        assert_eq!(fib.next(), Some(8));
+        assert_eq!(fib.next(), Some(13));
+    }
+
+    #[test]
+    fn fibonacci_nth_after_next() {
+        let mut fib = Fibonacci::default();
+        assert_eq!(fib.next(), Some(0));
+        assert_eq!(fib.next(), Some(1));
+        assert_eq!(fib.nth(1), Some(2));
+        assert_eq!(fib.nth(2), Some(8));
+    }
+
+    #[test]
+    fn fibonacci_below() {
+        assert_eq!(
+            Fibonacci::below(20).collect::<Vec<_>>(),
+            vec![0, 1, 1, 2, 3, 5, 8, 13]
+        );
+        assert_eq!(Fibonacci::below(21).last(), Some(13));
+        assert_eq!(Fibonacci::below(0).count(), 0);
+        assert_eq!(Fibonacci::below(1).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn fibonacci_below_max_does_not_overflow() {
+        assert_eq!(Fibonacci::below(u64::MAX).count(), 94);
+        assert_eq!(Fibonacci::below(u64::MAX).last(), Some(12200160415121876738));
+    }
+
+    #[test]
+    fn fibonacci_nth_past_the_end() {
+        let mut fib = Fibonacci::default();
+        assert_eq!(fib.next(), Some(0));
+        assert_eq!(fib.nth(usize::MAX), None);
+        assert_eq!(Fibonacci::default().nth(1_000_000), None);
+        assert_eq!(Fibonacci::default().nth(93), Some(12200160415121876738));
+    }
+}
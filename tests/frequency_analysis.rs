@@ -0,0 +1,162 @@
+//! Run this file with `cargo test --test frequency_analysis`.
+
+//! TODO: Implement letter frequency analysis of natural language text.
+//!
+//! - `letter_frequency`: counts the letters `a` to `z` in a text (case insensitively, ignoring
+//!   all other characters) and returns every letter that occurs together with its frequency as a
+//!   percentage of all counted letters. The result is sorted by frequency, most frequent first;
+//!   letters with the same frequency are sorted alphabetically.
+//! - `likely_language`: compares the letter frequencies of a text against the typical profiles of
+//!   English, Spanish and French, and returns the name of the closest one (`"english"`,
+//!   `"spanish"` or `"french"`). The distance between two profiles is the sum of squared
+//!   differences of the letter percentages.
+//!
+//! Accented letters (`é`, `ñ`, ...) are ignored, the profiles only cover the letters `a` to `z`.
+
+/// Typical percentages of the letters `a` to `z` in each language.
+const PROFILES: [(&str, [f64; 26]); 3] = [
+    (
+        "english",
+        [
+            8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025,
+            2.406, 6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150,
+            1.974, 0.074,
+        ],
+    ),
+    (
+        "spanish",
+        [
+            11.525, 2.215, 4.019, 5.010, 12.181, 0.692, 1.768, 0.703, 6.247, 0.493, 0.011, 4.967,
+            3.157, 6.712, 8.683, 2.510, 0.877, 6.871, 7.977, 4.632, 2.927, 1.138, 0.017, 0.215,
+            1.008, 0.467,
+        ],
+    ),
+    (
+        "french",
+        [
+            7.636, 0.901, 3.260, 3.669, 14.715, 1.066, 0.866, 0.737, 7.529, 0.613, 0.074, 5.456,
+            2.968, 7.095, 5.796, 2.521, 1.362, 6.693, 7.948, 7.244, 6.311, 1.838, 0.049, 0.427,
+            0.128, 0.326,
+        ],
+    ),
+];
+
+/// Percentages of the letters `a` to `z`, indexed by `letter - 'a'`.
+fn letter_percentages(text: &str) -> [f64; 26] {
+    let mut counts = [0u64; 26];
+
+    for c in text.chars().filter(|c| c.is_ascii_alphabetic()) {
+        counts[(c.to_ascii_lowercase() as u8 - b'a') as usize] += 1;
+    }
+
+    let total: u64 = counts.iter().sum();
+    let mut percentages = [0.0; 26];
+    if total > 0 {
+        for (percentage, count) in percentages.iter_mut().zip(counts) {
+            *percentage = count as f64 * 100.0 / total as f64;
+        }
+    }
+
+    percentages
+}
+
+fn letter_frequency(text: &str) -> Vec<(char, f64)> {
+    let mut frequencies: Vec<(char, f64)> = letter_percentages(text)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, percentage)| *percentage > 0.0)
+        .map(|(idx, percentage)| ((b'a' + idx as u8) as char, percentage))
+        .collect();
+
+    frequencies.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    frequencies
+}
+
+fn likely_language(text: &str) -> &'static str {
+    let percentages = letter_percentages(text);
+    let distance = |profile: &[f64; 26]| -> f64 {
+        profile
+            .iter()
+            .zip(percentages)
+            .map(|(expected, actual)| (expected - actual).powi(2))
+            .sum()
+    };
+
+    PROFILES
+        .iter()
+        .min_by(|a, b| distance(&a.1).total_cmp(&distance(&b.1)))
+        .map(|(language, _)| *language)
+        .unwrap()
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{letter_frequency, likely_language};
+
+    const ENGLISH: &str = "It was the best of times, it was the worst of times, it was the age of \
+        wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch of \
+        incredulity, it was the season of Light, it was the season of Darkness, it was the spring \
+        of hope, it was the winter of despair, we had everything before us, we had nothing before \
+        us, we were all going direct to Heaven, we were all going direct the other way.";
+
+    const SPANISH: &str = "En un lugar de la Mancha, de cuyo nombre no quiero acordarme, no ha \
+        mucho tiempo que vivía un hidalgo de los de lanza en astillero, adarga antigua, rocín \
+        flaco y galgo corredor. Una olla de algo más vaca que carnero, salpicón las más noches, \
+        duelos y quebrantos los sábados, lentejas los viernes, algún palomino de añadidura los \
+        domingos, consumían las tres partes de su hacienda.";
+
+    const FRENCH: &str = "Longtemps, je me suis couché de bonne heure. Parfois, à peine ma bougie \
+        éteinte, mes yeux se fermaient si vite que je n'avais pas le temps de me dire: Je \
+        m'endors. Et, une demi-heure après, la pensée qu'il était temps de chercher le sommeil \
+        m'éveillait; je voulais poser le volume que je croyais avoir dans les mains et souffler \
+        ma lumière.";
+
+    #[test]
+    fn frequency_empty() {
+        assert!(letter_frequency("").is_empty());
+        assert!(letter_frequency("123 !?").is_empty());
+    }
+
+    #[test]
+    fn frequency_single_letter() {
+        assert_eq!(letter_frequency("aAa"), vec![('a', 100.0)]);
+    }
+
+    #[test]
+    fn frequency_sorted_descending() {
+        assert_eq!(
+            letter_frequency("Hello, hello!"),
+            vec![('l', 40.0), ('e', 20.0), ('h', 20.0), ('o', 20.0)]
+        );
+    }
+
+    #[test]
+    fn frequency_sums_to_hundred() {
+        let total: f64 = letter_frequency(ENGLISH).iter().map(|(_, f)| f).sum();
+        assert!((total - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn frequency_english_top_letter() {
+        let frequencies = letter_frequency(ENGLISH);
+        assert!(frequencies.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(frequencies.iter().all(|(c, _)| c.is_ascii_lowercase()));
+        assert_eq!(frequencies[0].0, 'e');
+    }
+
+    #[test]
+    fn detect_english() {
+        assert_eq!(likely_language(ENGLISH), "english");
+    }
+
+    #[test]
+    fn detect_spanish() {
+        assert_eq!(likely_language(SPANISH), "spanish");
+    }
+
+    #[test]
+    fn detect_french() {
+        assert_eq!(likely_language(FRENCH), "french");
+    }
+}
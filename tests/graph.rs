@@ -0,0 +1,207 @@
+//! Run this file with `cargo test --test graph`.
+
+//! TODO: Implement an undirected graph stored as adjacency lists, with breadth-first and
+//! depth-first traversals.
+//!
+//! - `add_vertex`: adds a vertex, adding an existing vertex does nothing.
+//! - `add_edge`: connects two vertices, adding them first if they do not exist yet.
+//! - `bfs`: returns the vertices reachable from `start` in breadth-first order.
+//! - `dfs`: returns the vertices reachable from `start` in depth-first (pre-)order.
+//! - `connected_components`: groups all vertices into connected components.
+//!
+//! To make the traversals deterministic, neighbours are visited in the order in which their edges
+//! were added, and components are listed in the order in which their first vertex was added, with
+//! the vertices of each component in breadth-first order. Traversals from a vertex that is not in
+//! the graph return an empty list.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+struct Graph<T: Clone + Eq + Hash> {
+    /// Vertices in insertion order.
+    vertices: Vec<T>,
+    adjacency: HashMap<T, Vec<T>>,
+}
+
+impl<T: Clone + Eq + Hash> Graph<T> {
+    fn new() -> Self {
+        Self {
+            vertices: vec![],
+            adjacency: HashMap::new(),
+        }
+    }
+
+    fn add_vertex(&mut self, v: T) {
+        if !self.adjacency.contains_key(&v) {
+            self.vertices.push(v.clone());
+            self.adjacency.insert(v, vec![]);
+        }
+    }
+
+    fn add_edge(&mut self, a: T, b: T) {
+        self.add_vertex(a.clone());
+        self.add_vertex(b.clone());
+
+        let neighbours = self.adjacency.get_mut(&a).unwrap();
+        if neighbours.contains(&b) {
+            return;
+        }
+        neighbours.push(b.clone());
+
+        if a != b {
+            self.adjacency.get_mut(&b).unwrap().push(a);
+        }
+    }
+
+    fn neighbours(&self, v: &T) -> &[T] {
+        &self.adjacency[v]
+    }
+
+    fn bfs(&self, start: &T) -> Vec<&T> {
+        let Some((start, _)) = self.adjacency.get_key_value(start) else {
+            return vec![];
+        };
+
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        let mut order = vec![];
+
+        while let Some(vertex) = queue.pop_front() {
+            order.push(vertex);
+            for neighbour in self.neighbours(vertex) {
+                if visited.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        order
+    }
+
+    fn dfs(&self, start: &T) -> Vec<&T> {
+        let Some((start, _)) = self.adjacency.get_key_value(start) else {
+            return vec![];
+        };
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        let mut order = vec![];
+
+        while let Some(vertex) = stack.pop() {
+            if !visited.insert(vertex) {
+                continue;
+            }
+            order.push(vertex);
+            // Pushed in reverse, so that the first neighbour is visited first.
+            for neighbour in self.neighbours(vertex).iter().rev() {
+                if !visited.contains(neighbour) {
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        order
+    }
+
+    fn connected_components(&self) -> Vec<Vec<&T>> {
+        let mut visited = HashSet::new();
+        let mut components = vec![];
+
+        for vertex in &self.vertices {
+            if visited.contains(vertex) {
+                continue;
+            }
+            let component = self.bfs(vertex);
+            visited.extend(component.iter().copied());
+            components.push(component);
+        }
+
+        components
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    ///   1 - 2 - 4
+    ///   |   |
+    ///   3 - 5   6 - 7
+    fn sample_graph() -> Graph<u32> {
+        let mut graph = Graph::new();
+        graph.add_edge(1, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 4);
+        graph.add_edge(2, 5);
+        graph.add_edge(3, 5);
+        graph.add_edge(6, 7);
+        graph
+    }
+
+    #[test]
+    fn bfs_order() {
+        let graph = sample_graph();
+        assert_eq!(graph.bfs(&1), vec![&1, &2, &3, &4, &5]);
+        assert_eq!(graph.bfs(&5), vec![&5, &2, &3, &1, &4]);
+    }
+
+    #[test]
+    fn dfs_order() {
+        let graph = sample_graph();
+        assert_eq!(graph.dfs(&1), vec![&1, &2, &4, &5, &3]);
+        assert_eq!(graph.dfs(&3), vec![&3, &1, &2, &4, &5]);
+    }
+
+    #[test]
+    fn traversal_stays_in_component() {
+        let graph = sample_graph();
+        assert_eq!(graph.bfs(&6), vec![&6, &7]);
+        assert_eq!(graph.dfs(&7), vec![&7, &6]);
+    }
+
+    #[test]
+    fn unknown_start() {
+        let graph = sample_graph();
+        assert!(graph.bfs(&42).is_empty());
+        assert!(graph.dfs(&42).is_empty());
+    }
+
+    #[test]
+    fn single_node_graph() {
+        let mut graph = Graph::new();
+        graph.add_vertex("a");
+        assert_eq!(graph.bfs(&"a"), vec![&"a"]);
+        assert_eq!(graph.dfs(&"a"), vec![&"a"]);
+        assert_eq!(graph.connected_components(), vec![vec![&"a"]]);
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph: Graph<u32> = Graph::new();
+        assert!(graph.connected_components().is_empty());
+    }
+
+    #[test]
+    fn disconnected_components() {
+        let mut graph = sample_graph();
+        graph.add_vertex(8);
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![&1, &2, &3, &4, &5], vec![&6, &7], vec![&8]]
+        );
+    }
+
+    #[test]
+    fn duplicate_edges_and_self_loops() {
+        let mut graph = Graph::new();
+        graph.add_edge('a', 'b');
+        graph.add_edge('b', 'a');
+        graph.add_edge('a', 'a');
+        graph.add_vertex('a');
+        assert_eq!(graph.neighbours(&'a'), &['b', 'a']);
+        assert_eq!(graph.neighbours(&'b'), &['a']);
+        assert_eq!(graph.bfs(&'a'), vec![&'a', &'b']);
+        assert_eq!(graph.dfs(&'b'), vec![&'b', &'a']);
+    }
+}
@@ -0,0 +1,204 @@
+//! Run this file with `cargo test --test interval_map`.
+
+//! TODO: Implement a struct called `IntervalMap`, which maps disjoint half-open intervals
+//! `[start, end)` of `u64` points to values.
+//!
+//! Store the map as a `BTreeMap<u64, Option<V>>`, where every key is the start of an interval and
+//! its value applies to all points up to (but not including) the next key. `None` marks the start
+//! of a gap without a value. For example, after `set(2, 5, 'a')` the map contains
+//! `{2: Some('a'), 5: None}`.
+//!
+//! Implement the following methods:
+//! - `set`: assigns a value to all points in `[start, end)`, overwriting the parts of existing
+//!   intervals that overlap it. Neighbouring intervals with the same value are merged.
+//! - `get`: returns the value assigned to a point, if any.
+//! - `iter`: iterates over the intervals with a value, in ascending order.
+
+use std::collections::BTreeMap;
+
+struct IntervalMap<V: Clone> {
+    map: BTreeMap<u64, Option<V>>,
+}
+
+impl<V: Clone + PartialEq> IntervalMap<V> {
+    fn new() -> Self {
+        Self {
+            map: BTreeMap::new(),
+        }
+    }
+
+    fn entry_at(&self, point: u64) -> Option<&V> {
+        self.map
+            .range(..=point)
+            .next_back()
+            .and_then(|(_, value)| value.as_ref())
+    }
+
+    fn set(&mut self, start: u64, end: u64, value: V) {
+        if start >= end {
+            return;
+        }
+
+        let value_after = self.entry_at(end).cloned();
+        let overwritten: Vec<u64> = self.map.range(start..=end).map(|(key, _)| *key).collect();
+        for key in overwritten {
+            self.map.remove(&key);
+        }
+
+        // With the overwritten keys gone, this is the value of the point right before `start`.
+        if self.entry_at(start) != Some(&value) {
+            self.map.insert(start, Some(value.clone()));
+        }
+
+        if value_after.as_ref() != Some(&value) {
+            self.map.insert(end, value_after);
+        }
+
+        // A gap directly followed by another gap is redundant.
+        let mut after_end = self.map.range(end..).map(|(key, value)| (*key, value.is_none()));
+        if let (Some((_, true)), Some((next, true))) = (after_end.next(), after_end.next()) {
+            self.map.remove(&next);
+        }
+        if let Some((&first, None)) = self.map.first_key_value() {
+            self.map.remove(&first);
+        }
+    }
+
+    fn get(&self, point: u64) -> Option<&V> {
+        self.entry_at(point)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = ((u64, u64), &V)> {
+        self.map
+            .iter()
+            .zip(self.map.keys().skip(1))
+            .filter_map(|((start, value), end)| value.as_ref().map(|value| ((*start, *end), value)))
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::IntervalMap;
+
+    fn intervals(map: &IntervalMap<char>) -> Vec<((u64, u64), char)> {
+        map.iter().map(|(interval, value)| (interval, *value)).collect()
+    }
+
+    #[test]
+    fn empty_map() {
+        let map: IntervalMap<char> = IntervalMap::new();
+        assert_eq!(map.get(0), None);
+        assert_eq!(map.get(u64::MAX), None);
+        assert!(intervals(&map).is_empty());
+    }
+
+    #[test]
+    fn single_interval_boundaries() {
+        let mut map = IntervalMap::new();
+        map.set(10, 20, 'a');
+        assert_eq!(map.get(9), None);
+        assert_eq!(map.get(10), Some(&'a'));
+        assert_eq!(map.get(15), Some(&'a'));
+        assert_eq!(map.get(19), Some(&'a'));
+        assert_eq!(map.get(20), None);
+        assert_eq!(intervals(&map), vec![((10, 20), 'a')]);
+    }
+
+    #[test]
+    fn empty_interval_is_ignored() {
+        let mut map = IntervalMap::new();
+        map.set(5, 5, 'a');
+        map.set(7, 3, 'b');
+        assert!(intervals(&map).is_empty());
+    }
+
+    #[test]
+    fn interval_from_zero() {
+        let mut map = IntervalMap::new();
+        map.set(0, 3, 'a');
+        assert_eq!(map.get(0), Some(&'a'));
+        assert_eq!(map.get(3), None);
+        assert_eq!(intervals(&map), vec![((0, 3), 'a')]);
+    }
+
+    #[test]
+    fn gaps_between_intervals() {
+        let mut map = IntervalMap::new();
+        map.set(0, 5, 'a');
+        map.set(10, 15, 'b');
+        assert_eq!(map.get(4), Some(&'a'));
+        assert_eq!(map.get(5), None);
+        assert_eq!(map.get(9), None);
+        assert_eq!(map.get(10), Some(&'b'));
+        assert_eq!(intervals(&map), vec![((0, 5), 'a'), ((10, 15), 'b')]);
+    }
+
+    #[test]
+    fn overlapping_right_side() {
+        let mut map = IntervalMap::new();
+        map.set(0, 10, 'a');
+        map.set(5, 15, 'b');
+        assert_eq!(map.get(4), Some(&'a'));
+        assert_eq!(map.get(5), Some(&'b'));
+        assert_eq!(map.get(14), Some(&'b'));
+        assert_eq!(map.get(15), None);
+        assert_eq!(intervals(&map), vec![((0, 5), 'a'), ((5, 15), 'b')]);
+    }
+
+    #[test]
+    fn overlapping_left_side() {
+        let mut map = IntervalMap::new();
+        map.set(5, 15, 'a');
+        map.set(0, 10, 'b');
+        assert_eq!(intervals(&map), vec![((0, 10), 'b'), ((10, 15), 'a')]);
+    }
+
+    #[test]
+    fn splitting_an_interval() {
+        let mut map = IntervalMap::new();
+        map.set(0, 20, 'a');
+        map.set(5, 10, 'b');
+        assert_eq!(map.get(4), Some(&'a'));
+        assert_eq!(map.get(5), Some(&'b'));
+        assert_eq!(map.get(9), Some(&'b'));
+        assert_eq!(map.get(10), Some(&'a'));
+        assert_eq!(intervals(&map), vec![((0, 5), 'a'), ((5, 10), 'b'), ((10, 20), 'a')]);
+    }
+
+    #[test]
+    fn covering_several_intervals() {
+        let mut map = IntervalMap::new();
+        map.set(0, 5, 'a');
+        map.set(8, 12, 'b');
+        map.set(14, 20, 'c');
+        map.set(3, 16, 'd');
+        assert_eq!(intervals(&map), vec![((0, 3), 'a'), ((3, 16), 'd'), ((16, 20), 'c')]);
+    }
+
+    #[test]
+    fn merging_equal_values() {
+        let mut map = IntervalMap::new();
+        map.set(0, 10, 'a');
+        map.set(5, 15, 'a');
+        assert_eq!(intervals(&map), vec![((0, 15), 'a')]);
+
+        map.set(15, 20, 'a');
+        assert_eq!(intervals(&map), vec![((0, 20), 'a')]);
+
+        map.set(20, 25, 'b');
+        map.set(25, 30, 'b');
+        assert_eq!(intervals(&map), vec![((0, 20), 'a'), ((20, 30), 'b')]);
+        assert_eq!(map.map.len(), 3);
+    }
+
+    #[test]
+    fn filling_a_gap() {
+        let mut map = IntervalMap::new();
+        map.set(0, 5, 'a');
+        map.set(10, 15, 'a');
+        map.set(5, 10, 'a');
+        assert_eq!(intervals(&map), vec![((0, 15), 'a')]);
+        assert_eq!(map.map.len(), 2);
+    }
+}
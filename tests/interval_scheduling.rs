@@ -0,0 +1,126 @@
+//! Run this file with `cargo test --test interval_scheduling`.
+
+//! TODO: Implement a function called `max_activities`, which selects as many non-overlapping
+//! activities as possible and returns their indices.
+//!
+//! Every activity is given as `(start, end)` and occupies the half-open interval `[start, end)`,
+//! so an activity may start exactly when another one ends. You can assume that `start <= end`.
+//!
+//! Use the greedy algorithm: sort the activities by their end time and pick every activity that
+//! starts after the last picked one has ended. Picking the activity that ends first always leaves
+//! the most room for the others, which is why this finds a maximum set.
+//!
+//! Return the indices in the order in which they were picked, i.e. sorted by end time. Activities
+//! with the same end time are considered in the order of their indices.
+
+fn max_activities(intervals: &[(u64, u64)]) -> Vec<usize> {
+    let mut by_end: Vec<usize> = (0..intervals.len()).collect();
+    // The sort is stable, so ties keep the order of the indices.
+    by_end.sort_by_key(|&i| intervals[i].1);
+
+    let mut selected = vec![];
+    let mut free_from = 0;
+    for i in by_end {
+        let (start, end) = intervals[i];
+        if start >= free_from {
+            selected.push(i);
+            free_from = end;
+        }
+    }
+
+    selected
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::max_activities;
+
+    /// Finds the size of the largest non-overlapping set by trying every subset.
+    fn brute_force_max(intervals: &[(u64, u64)]) -> usize {
+        (0u32..1 << intervals.len())
+            .filter_map(|mask| {
+                let mut chosen: Vec<(u64, u64)> = (0..intervals.len())
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| intervals[i])
+                    .collect();
+                chosen.sort();
+                chosen
+                    .windows(2)
+                    .all(|pair| pair[0].1 <= pair[1].0)
+                    .then_some(chosen.len())
+            })
+            .max()
+            .unwrap()
+    }
+
+    fn assert_non_overlapping(intervals: &[(u64, u64)], selected: &[usize]) {
+        for pair in selected.windows(2) {
+            assert!(intervals[pair[0]].1 <= intervals[pair[1]].0, "{pair:?} overlap");
+        }
+    }
+
+    #[test]
+    fn no_activities() {
+        assert!(max_activities(&[]).is_empty());
+    }
+
+    #[test]
+    fn single_activity() {
+        assert_eq!(max_activities(&[(3, 7)]), vec![0]);
+    }
+
+    #[test]
+    fn classic_example() {
+        let intervals = [(1, 2), (3, 4), (0, 6), (5, 7), (8, 9), (5, 9)];
+        assert_eq!(max_activities(&intervals), vec![0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn textbook_example() {
+        let intervals = [
+            (1, 4),
+            (3, 5),
+            (0, 6),
+            (5, 7),
+            (3, 9),
+            (5, 9),
+            (6, 10),
+            (8, 11),
+            (8, 12),
+            (2, 14),
+            (12, 16),
+        ];
+        assert_eq!(max_activities(&intervals), vec![0, 3, 7, 10]);
+    }
+
+    #[test]
+    fn touching_activities() {
+        assert_eq!(max_activities(&[(2, 4), (0, 2), (4, 6)]), vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn long_activity_loses() {
+        assert_eq!(max_activities(&[(0, 100), (10, 20), (30, 40)]), vec![1, 2]);
+    }
+
+    #[test]
+    fn identical_activities() {
+        assert_eq!(max_activities(&[(1, 3), (1, 3), (1, 3)]), vec![0]);
+    }
+
+    #[test]
+    fn optimal_on_many_inputs() {
+        let samples: [&[(u64, u64)]; 4] = [
+            &[(0, 3), (2, 5), (4, 7), (6, 9), (1, 2), (8, 10)],
+            &[(0, 10), (0, 1), (1, 2), (2, 3), (9, 10)],
+            &[(5, 6), (4, 6), (3, 6), (0, 5), (0, 4), (1, 3)],
+            &[(0, 0), (0, 0), (0, 1), (1, 1)],
+        ];
+        for intervals in samples {
+            let selected = max_activities(intervals);
+            assert_non_overlapping(intervals, &selected);
+            assert_eq!(selected.len(), brute_force_max(intervals), "{intervals:?}");
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Run this file with `cargo test --test knapsack`.
+
+//! TODO: Solve the [0/1 knapsack problem](https://en.wikipedia.org/wiki/Knapsack_problem): given
+//! items with weights and values, choose items with the largest total value whose total weight
+//! does not exceed `capacity`. Every item can be taken at most once.
+//!
+//! - `knapsack`: returns the largest achievable total value.
+//! - `knapsack_items`: returns the indices of the chosen items, in ascending order.
+//!
+//! Item `i` has the weight `weights[i]` and the value `values[i]`. Both functions should panic if
+//! `weights` and `values` have different lengths.
+//!
+//! Use a 2D dynamic programming table, where `best[i][c]` is the largest value achievable with
+//! the first `i` items and a capacity of `c`. The chosen items can then be recovered by walking
+//! back through the table.
+
+/// Builds the table of the best values for every prefix of the items and every capacity.
+fn knapsack_table(capacity: usize, weights: &[usize], values: &[usize]) -> Vec<Vec<usize>> {
+    assert_eq!(
+        weights.len(),
+        values.len(),
+        "every item needs both a weight and a value"
+    );
+
+    let mut best = vec![vec![0; capacity + 1]; weights.len() + 1];
+    for (i, (&weight, &value)) in weights.iter().zip(values).enumerate() {
+        for c in 0..=capacity {
+            best[i + 1][c] = best[i][c];
+            if weight <= c {
+                best[i + 1][c] = best[i + 1][c].max(best[i][c - weight] + value);
+            }
+        }
+    }
+
+    best
+}
+
+fn knapsack(capacity: usize, weights: &[usize], values: &[usize]) -> usize {
+    knapsack_table(capacity, weights, values)[weights.len()][capacity]
+}
+
+fn knapsack_items(capacity: usize, weights: &[usize], values: &[usize]) -> Vec<usize> {
+    let best = knapsack_table(capacity, weights, values);
+
+    let mut items = vec![];
+    let mut c = capacity;
+    for i in (0..weights.len()).rev() {
+        // If the best value changed when item `i` became available, it was taken.
+        if best[i + 1][c] != best[i][c] {
+            items.push(i);
+            c -= weights[i];
+        }
+    }
+
+    items.reverse();
+    items
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{knapsack, knapsack_items};
+
+    /// Checks that `items` fit into `capacity` and add up to `expected_value`.
+    fn check_items(capacity: usize, weights: &[usize], values: &[usize], expected_value: usize) {
+        let items = knapsack_items(capacity, weights, values);
+        assert!(items.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(items.iter().map(|&i| weights[i]).sum::<usize>() <= capacity);
+        assert_eq!(items.iter().map(|&i| values[i]).sum::<usize>(), expected_value);
+    }
+
+    #[test]
+    fn classic_example() {
+        let weights = [10, 20, 30];
+        let values = [60, 100, 120];
+        assert_eq!(knapsack(50, &weights, &values), 220);
+        assert_eq!(knapsack_items(50, &weights, &values), vec![1, 2]);
+    }
+
+    #[test]
+    fn greedy_by_ratio_is_not_enough() {
+        // Item 0 has the best value per weight, but taking it leaves no room for anything else.
+        let weights = [6, 5, 5];
+        let values = [13, 10, 10];
+        assert_eq!(knapsack(10, &weights, &values), 20);
+        assert_eq!(knapsack_items(10, &weights, &values), vec![1, 2]);
+    }
+
+    #[test]
+    fn larger_example() {
+        let weights = [23, 31, 29, 44, 53, 38, 63, 85, 89, 82];
+        let values = [92, 57, 49, 68, 60, 43, 67, 84, 87, 72];
+        assert_eq!(knapsack(165, &weights, &values), 309);
+        assert_eq!(knapsack_items(165, &weights, &values), vec![0, 1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn everything_fits() {
+        let weights = [1, 2, 3];
+        let values = [4, 5, 6];
+        assert_eq!(knapsack(100, &weights, &values), 15);
+        assert_eq!(knapsack_items(100, &weights, &values), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn empty_items() {
+        assert_eq!(knapsack(10, &[], &[]), 0);
+        assert!(knapsack_items(10, &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        assert_eq!(knapsack(0, &[1, 2], &[10, 20]), 0);
+        assert!(knapsack_items(0, &[1, 2], &[10, 20]).is_empty());
+        // Weightless items always fit.
+        assert_eq!(knapsack(0, &[0, 1], &[7, 20]), 7);
+        assert_eq!(knapsack_items(0, &[0, 1], &[7, 20]), vec![0]);
+    }
+
+    #[test]
+    fn chosen_items_are_optimal() {
+        let weights = [4, 2, 3, 1, 5, 2, 6];
+        let values = [5, 3, 4, 2, 6, 3, 7];
+        for capacity in 0..=25 {
+            check_items(capacity, &weights, &values, knapsack(capacity, &weights, &values));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "every item needs both a weight and a value")]
+    fn mismatched_lengths() {
+        knapsack(10, &[1, 2], &[3]);
+    }
+}
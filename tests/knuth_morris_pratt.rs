@@ -0,0 +1,134 @@
+//! Run this file with `cargo test --test knuth_morris_pratt`.
+
+//! TODO: Implement a function called `kmp_search`, which returns the byte offsets of all
+//! occurrences of `pattern` in `text`, including overlapping ones, using the
+//! [Knuth-Morris-Pratt algorithm](https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm).
+//!
+//! Comparing the pattern at every offset anew takes O(n·m) time. KMP never moves backwards in the
+//! text: when a character does not match, it continues with the longest prefix of the pattern
+//! that is still known to match, which makes it run in O(n + m).
+//!
+//! The empty pattern occurs at every character boundary of the text, including its end, just like
+//! with `str::match_indices`.
+//!
+//! Hint: first compute the "failure function" of the pattern: for every prefix of the pattern,
+//! the length of its longest proper prefix that is also a suffix of it. Comparing the bytes of
+//! UTF-8 strings is fine, a match of the bytes always starts at a character boundary.
+
+/// Returns, for every `i`, the length of the longest proper prefix of `pattern[..=i]` that is
+/// also a suffix of it.
+fn failure_function(pattern: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0; pattern.len()];
+    let mut matched = 0;
+
+    for i in 1..pattern.len() {
+        while matched > 0 && pattern[i] != pattern[matched] {
+            matched = failure[matched - 1];
+        }
+        if pattern[i] == pattern[matched] {
+            matched += 1;
+        }
+        failure[i] = matched;
+    }
+
+    failure
+}
+
+fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        return text
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain([text.len()])
+            .collect();
+    }
+
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    let failure = failure_function(pattern);
+    let mut matches = vec![];
+    let mut matched = 0;
+
+    for (i, &byte) in text.iter().enumerate() {
+        while matched > 0 && byte != pattern[matched] {
+            matched = failure[matched - 1];
+        }
+        if byte == pattern[matched] {
+            matched += 1;
+        }
+        if matched == pattern.len() {
+            matches.push(i + 1 - pattern.len());
+            matched = failure[matched - 1];
+        }
+    }
+
+    matches
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{failure_function, kmp_search};
+
+    fn naive_search(text: &str, pattern: &str) -> Vec<usize> {
+        (0..=text.len())
+            .filter(|&i| text.is_char_boundary(i) && text[i..].starts_with(pattern))
+            .collect()
+    }
+
+    #[test]
+    fn failure_function_values() {
+        assert_eq!(failure_function(b"ababaca"), vec![0, 0, 1, 2, 3, 0, 1]);
+        assert_eq!(failure_function(b"aaaa"), vec![0, 1, 2, 3]);
+        assert_eq!(failure_function(b"abcd"), vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn single_match() {
+        assert_eq!(kmp_search("hello world", "world"), vec![6]);
+        assert_eq!(kmp_search("hello world", "hello world"), vec![0]);
+    }
+
+    #[test]
+    fn no_match() {
+        assert!(kmp_search("hello world", "worlds").is_empty());
+        assert!(kmp_search("abc", "abcd").is_empty());
+        assert!(kmp_search("", "a").is_empty());
+    }
+
+    #[test]
+    fn multiple_matches() {
+        assert_eq!(kmp_search("one two one two one", "one"), vec![0, 8, 16]);
+        assert_eq!(kmp_search("abcabdabcabc", "abc"), vec![0, 6, 9]);
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        assert_eq!(kmp_search("aaaa", "aa"), vec![0, 1, 2]);
+        assert_eq!(kmp_search("abababab", "abab"), vec![0, 2, 4]);
+        assert_eq!(kmp_search("aabaabaab", "aabaab"), vec![0, 3]);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(kmp_search("abc", ""), vec![0, 1, 2, 3]);
+        assert_eq!(kmp_search("", ""), vec![0]);
+        assert_eq!(kmp_search("äb", ""), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn byte_offsets_in_unicode_text() {
+        assert_eq!(kmp_search("größer größe", "öß"), vec![2, 11]);
+        assert_eq!(kmp_search("日本日本", "本"), vec![3, 9]);
+    }
+
+    #[test]
+    fn matches_naive_search() {
+        let texts = ["abracadabra", "aaaaaaaab", "mississippi", "ababcabababcab", "ß ßß"];
+        let patterns = ["a", "abra", "aa", "aab", "issi", "ss", "ababc", "ßß", "b", "ab"];
+        for text in texts {
+            for pattern in patterns {
+                assert_eq!(kmp_search(text, pattern), naive_search(text, pattern), "{text} {pattern}");
+            }
+        }
+    }
+}
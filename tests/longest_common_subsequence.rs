@@ -0,0 +1,125 @@
+//! Run this file with `cargo test --test longest_common_subsequence`.
+
+//! TODO: Find the [longest common subsequence](https://en.wikipedia.org/wiki/Longest_common_subsequence)
+//! of two strings, compared character by character. A subsequence is what remains after deleting
+//! any characters, e.g. `"ace"` is a subsequence of `"abcde"`.
+//!
+//! - `lcs_length`: the length (in characters) of the longest common subsequence.
+//! - `lcs`: one longest common subsequence. There may be several, return any of them.
+//!
+//! Hint: let `l[i][j]` be the length of the longest common subsequence of the first `i`
+//! characters of `a` and the first `j` characters of `b`. If the `i`-th character of `a` equals
+//! the `j`-th character of `b`, it is `l[i - 1][j - 1] + 1`, otherwise the larger of `l[i - 1][j]`
+//! and `l[i][j - 1]`. To reconstruct the subsequence, walk back from `l[a.len()][b.len()]`,
+//! following the choices that produced every value.
+
+/// Computes the full table `l`, where `l[i][j]` is the length of the longest common subsequence
+/// of `a[..i]` and `b[..j]`.
+fn lcs_table(a: &[char], b: &[char]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                table[i - 1][j - 1] + 1
+            } else {
+                table[i - 1][j].max(table[i][j - 1])
+            };
+        }
+    }
+    table
+}
+
+fn lcs_length(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    lcs_table(&a, &b)[a.len()][b.len()]
+}
+
+fn lcs(a: &str, b: &str) -> String {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let table = lcs_table(&a, &b);
+
+    let mut subsequence = vec![];
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            subsequence.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    subsequence.iter().rev().collect()
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{lcs, lcs_length};
+
+    fn is_subsequence(sub: &str, s: &str) -> bool {
+        let mut chars = s.chars();
+        sub.chars().all(|c| chars.any(|d| d == c))
+    }
+
+    fn assert_lcs(a: &str, b: &str, expected_length: usize) {
+        let result = lcs(a, b);
+        assert_eq!(lcs_length(a, b), expected_length, "{a} {b}");
+        assert_eq!(result.chars().count(), expected_length, "{a} {b}");
+        assert!(is_subsequence(&result, a), "{result} in {a}");
+        assert!(is_subsequence(&result, b), "{result} in {b}");
+    }
+
+    #[test]
+    fn textbook_example() {
+        assert_eq!(lcs_length("ABCBDAB", "BDCAB"), 4);
+        let result = lcs("ABCBDAB", "BDCAB");
+        assert!(["BCAB", "BDAB", "BCBA"].contains(&result.as_str()), "{result}");
+        assert_lcs("ABCBDAB", "BDCAB", 4);
+    }
+
+    #[test]
+    fn empty_strings() {
+        assert_eq!(lcs_length("", ""), 0);
+        assert_eq!(lcs("", "abc"), "");
+        assert_eq!(lcs("abc", ""), "");
+    }
+
+    #[test]
+    fn identical_strings() {
+        assert_eq!(lcs("rustacean", "rustacean"), "rustacean");
+        assert_eq!(lcs_length("rustacean", "rustacean"), 9);
+    }
+
+    #[test]
+    fn nothing_in_common() {
+        assert_eq!(lcs("abc", "xyz"), "");
+        assert_eq!(lcs_length("abc", "xyz"), 0);
+    }
+
+    #[test]
+    fn known_pairs() {
+        assert_eq!(lcs("AGGTAB", "GXTXAYB"), "GTAB");
+        assert_eq!(lcs("abcde", "ace"), "ace");
+        assert_lcs("XMJYAUZ", "MZJAWXU", 4);
+        assert_lcs("ababab", "bababa", 5);
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(lcs("héllo wörld", "hllö wrld"), "hll wrld");
+        assert_lcs("日本語のテキスト", "日本のテスト", 6);
+    }
+
+    #[test]
+    fn subsequence_of_longer_string() {
+        assert_eq!(lcs("a1b2c3d4", "abcd"), "abcd");
+        assert_eq!(lcs("abcd", "a1b2c3d4"), "abcd");
+    }
+}
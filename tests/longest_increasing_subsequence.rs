@@ -0,0 +1,136 @@
+//! Run this file with `cargo test --test longest_increasing_subsequence`.
+
+//! TODO: Find the [longest strictly increasing subsequence](https://en.wikipedia.org/wiki/Longest_increasing_subsequence)
+//! of a sequence of numbers in O(n log n) time.
+//!
+//! - `lis_length`: the length of the longest increasing subsequence.
+//! - `lis`: one longest increasing subsequence. There may be several, return any of them.
+//!
+//! Hint: go through the values and keep, for every length `l`, the smallest value that an
+//! increasing subsequence of length `l` found so far can end with ("patience sorting"). These
+//! tail values are increasing, so a binary search finds which one a new value replaces, or whether
+//! it extends the longest subsequence. To reconstruct the subsequence, additionally remember for
+//! every value the index of the value before it.
+
+fn lis_length(values: &[i64]) -> usize {
+    // `tails[l]` is the smallest value an increasing subsequence of length `l + 1` can end with.
+    let mut tails: Vec<i64> = vec![];
+    for &value in values {
+        let position = tails.partition_point(|&tail| tail < value);
+        if position == tails.len() {
+            tails.push(value);
+        } else {
+            tails[position] = value;
+        }
+    }
+    tails.len()
+}
+
+fn lis(values: &[i64]) -> Vec<i64> {
+    // Like in `lis_length`, but `tails` stores indices into `values`.
+    let mut tails: Vec<usize> = vec![];
+    // For every index, the index of the value before it in the subsequence ending at it.
+    let mut previous: Vec<Option<usize>> = vec![None; values.len()];
+
+    for (index, &value) in values.iter().enumerate() {
+        let position = tails.partition_point(|&tail| values[tail] < value);
+        if position > 0 {
+            previous[index] = Some(tails[position - 1]);
+        }
+        if position == tails.len() {
+            tails.push(index);
+        } else {
+            tails[position] = index;
+        }
+    }
+
+    let mut subsequence = vec![];
+    let mut current = tails.last().copied();
+    while let Some(index) = current {
+        subsequence.push(values[index]);
+        current = previous[index];
+    }
+    subsequence.reverse();
+    subsequence
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{lis, lis_length};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Checks that `subsequence` is strictly increasing and can be obtained from `values`.
+    fn assert_increasing_subsequence(subsequence: &[i64], values: &[i64]) {
+        assert!(subsequence.windows(2).all(|pair| pair[0] < pair[1]), "{subsequence:?}");
+        let mut remaining = values.iter();
+        assert!(
+            subsequence.iter().all(|x| remaining.any(|y| x == y)),
+            "{subsequence:?} in {values:?}"
+        );
+    }
+
+    /// The quadratic dynamic programming solution.
+    fn quadratic_lis_length(values: &[i64]) -> usize {
+        let mut lengths = vec![1; values.len()];
+        for i in 0..values.len() {
+            for j in 0..i {
+                if values[j] < values[i] {
+                    lengths[i] = lengths[i].max(lengths[j] + 1);
+                }
+            }
+        }
+        lengths.into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn classic_example() {
+        let values = [10, 9, 2, 5, 3, 7, 101, 18];
+        assert_eq!(lis_length(&values), 4);
+        let result = lis(&values);
+        assert_eq!(result.len(), 4);
+        assert_increasing_subsequence(&result, &values);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(lis_length(&[]), 0);
+        assert_eq!(lis(&[]), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn strictly_decreasing() {
+        let values = [5, 4, 3, 2, 1];
+        assert_eq!(lis_length(&values), 1);
+        assert_eq!(lis(&values).len(), 1);
+    }
+
+    #[test]
+    fn duplicates_do_not_count() {
+        assert_eq!(lis_length(&[7, 7, 7, 7]), 1);
+        assert_eq!(lis(&[1, 2, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn already_sorted() {
+        let values = [-3, -1, 0, 4, 9];
+        assert_eq!(lis_length(&values), 5);
+        assert_eq!(lis(&values), values);
+    }
+
+    #[test]
+    fn matches_quadratic_solution() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..200 {
+            let len = rng.random_range(0..40);
+            let values: Vec<i64> = (0..len).map(|_| rng.random_range(-20..20)).collect();
+            let expected = quadratic_lis_length(&values);
+            assert_eq!(lis_length(&values), expected, "{values:?}");
+
+            let result = lis(&values);
+            assert_eq!(result.len(), expected, "{values:?}");
+            assert_increasing_subsequence(&result, &values);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Run this file with `cargo test --test longest_palindrome`.
+
+//! TODO: Implement a function called `longest_palindrome`, which returns the longest substring of
+//! `s` that reads the same forwards and backwards (compared character by character). If there are
+//! several longest palindromes, return the first one. The empty string is its own longest
+//! palindrome.
+//!
+//! Expanding around every possible center takes O(n^2) time. Instead, use
+//! [Manacher's algorithm](https://en.wikipedia.org/wiki/Longest_palindromic_substring#Manacher's_algorithm),
+//! which finds the longest palindrome in O(n) time.
+//!
+//! Hint: to handle odd and even palindromes the same way, imagine a separator between every two
+//! characters (and at both ends), so that every palindrome has a single center. While scanning the
+//! centers from left to right, keep track of the palindrome that reaches furthest to the right. A
+//! center inside it starts with at least the radius of its mirror image, so characters are never
+//! compared twice.
+
+fn longest_palindrome(s: &str) -> &str {
+    // Byte offset of every character, plus the end of the string.
+    let offsets: Vec<usize> = s
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain([s.len()])
+        .collect();
+    let chars: Vec<char> = s.chars().collect();
+
+    // Even positions are separators, odd positions are characters.
+    let at = |i: usize| (i % 2 == 1).then(|| chars[i / 2]);
+    let len = 2 * chars.len() + 1;
+
+    // `radius[i]` is the number of characters of the longest palindrome centered at `i`, which is
+    // also how far it extends to each side (in positions).
+    let mut radius = vec![0; len];
+    let (mut center, mut right) = (0, 0);
+    let (mut best_center, mut best_radius) = (0, 0);
+
+    for i in 0..len {
+        let mut r = if i < right {
+            radius[2 * center - i].min(right - i)
+        } else {
+            0
+        };
+        while i > r && i + r + 1 < len && at(i - r - 1) == at(i + r + 1) {
+            r += 1;
+        }
+        radius[i] = r;
+
+        if i + r > right {
+            center = i;
+            right = i + r;
+        }
+        if r > best_radius {
+            best_center = i;
+            best_radius = r;
+        }
+    }
+
+    let start = (best_center - best_radius) / 2;
+    &s[offsets[start]..offsets[start + best_radius]]
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::longest_palindrome;
+
+    /// Checks every substring, for comparison.
+    fn naive_longest_palindrome(s: &str) -> String {
+        let chars: Vec<char> = s.chars().collect();
+        let mut best: &[char] = &[];
+        for start in 0..chars.len() {
+            for end in start + 1..=chars.len() {
+                let candidate = &chars[start..end];
+                if candidate.len() > best.len() && candidate.iter().eq(candidate.iter().rev()) {
+                    best = candidate;
+                }
+            }
+        }
+        best.iter().collect()
+    }
+
+    #[test]
+    fn empty_string() {
+        assert_eq!(longest_palindrome(""), "");
+    }
+
+    #[test]
+    fn single_character() {
+        assert_eq!(longest_palindrome("a"), "a");
+        assert_eq!(longest_palindrome("ž"), "ž");
+    }
+
+    #[test]
+    fn odd_length() {
+        assert_eq!(longest_palindrome("babad"), "bab");
+        assert_eq!(longest_palindrome("xracecarz"), "racecar");
+        assert_eq!(longest_palindrome("aaa"), "aaa");
+    }
+
+    #[test]
+    fn even_length() {
+        assert_eq!(longest_palindrome("cbbd"), "bb");
+        assert_eq!(longest_palindrome("xabbay"), "abba");
+        assert_eq!(longest_palindrome("aaaa"), "aaaa");
+    }
+
+    #[test]
+    fn no_repeated_characters() {
+        assert_eq!(longest_palindrome("abcdef"), "a");
+        assert_eq!(longest_palindrome("zyx"), "z");
+    }
+
+    #[test]
+    fn first_of_ties() {
+        assert_eq!(longest_palindrome("abacdc"), "aba");
+        assert_eq!(longest_palindrome("aabb"), "aa");
+    }
+
+    #[test]
+    fn whole_string() {
+        assert_eq!(
+            longest_palindrome("amanaplanacanalpanama"),
+            "amanaplanacanalpanama"
+        );
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(longest_palindrome("xčřčy"), "čřč");
+        assert_eq!(longest_palindrome("🦀ab🦀🦀ba"), "ab🦀🦀ba");
+    }
+
+    #[test]
+    fn matches_naive() {
+        for s in [
+            "abaxabaxabb",
+            "forgeeksskeegfor",
+            "abacabadabacaba",
+            "bananas",
+            "aabbaaabba",
+            "cabbacxyzzyx",
+        ] {
+            assert_eq!(longest_palindrome(s), naive_longest_palindrome(s), "{s}");
+        }
+    }
+}
@@ -1,15 +1,17 @@
+use std::error::Error;
+use std::fmt::Display;
+
 fn luhn_algorithm(n: u64) -> bool {
     let n_string = n.to_string();
     let length = n_string.len();
-    let mut sum = 0;
+    let mut sum: u32 = 0;
     let parity = length % 2;
 
     for i in (0..(length - 1)).rev() {
-        let card_number = &n_string[i..i+1].parse::<i32>().unwrap();
-        let mut d = 0;
+        let card_number = n_string[i..i+1].parse::<u32>().unwrap();
 
         if  i % 2 == parity {
-            d = 2 * *card_number;
+            let mut d = 2 * card_number;
 
             if d > 9 {
                 d -= 9;
@@ -17,19 +19,141 @@ fn luhn_algorithm(n: u64) -> bool {
 
             sum += d;
         } else {
-            sum += *card_number;
+            sum += card_number;
         }
     }
 
-    let last_num = &n_string[length - 1..length].parse::<i32>().unwrap();
+    let last_num = n_string[length - 1..length].parse::<u32>().unwrap();
+
+    last_num == ((10 - (sum % 10)) % 10)
+}
+
+/// Same check as `luhn_algorithm` for wider numbers, extracting the digits arithmetically
+/// instead of going through a string.
+fn luhn_check_u128(n: u128) -> bool {
+    let mut remaining = n;
+    let mut sum: u32 = 0;
+    let mut position = 0;
+
+    loop {
+        let mut digit = (remaining % 10) as u32;
+        remaining /= 10;
+
+        // Every second digit from the right, excluding the check digit, is doubled.
+        if position % 2 == 1 {
+            digit *= 2;
+
+            if digit > 9 {
+                digit -= 9;
+            }
+        }
+
+        sum += digit;
+        position += 1;
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    sum.is_multiple_of(10)
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum LuhnError {
+    Empty,
+    InvalidDigit { location: usize, character: char },
+    ZeroGroupSize,
+}
+
+impl Display for LuhnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for LuhnError {}
+
+fn parse_digits(s: &str) -> Result<Vec<u32>, LuhnError> {
+    if s.is_empty() {
+        return Err(LuhnError::Empty);
+    }
+
+    s.chars()
+        .enumerate()
+        .map(|(location, character)| {
+            character
+                .to_digit(10)
+                .ok_or(LuhnError::InvalidDigit { location, character })
+        })
+        .collect()
+}
+
+/// The contribution of a doubled digit in the standard algorithm: `2 * d`, minus 9 if that is
+/// larger than 9.
+const STANDARD_DOUBLE_MAP: [u8; 10] = [0, 2, 4, 6, 8, 1, 3, 5, 7, 9];
+
+/// Sums the digits of a card number given as a string of digits, where every doubled digit `d`
+/// contributes `double_map[d]` instead of `d`.
+fn weighted_sum(s: &str, double_map: &[u8; 10]) -> Result<u32, LuhnError> {
+    let digits = parse_digits(s)?;
+
+    Ok(digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(position, &digit)| {
+            if position % 2 == 1 {
+                double_map[digit as usize] as u32
+            } else {
+                digit
+            }
+        })
+        .sum())
+}
+
+/// Returns the sum of the weighted digits of a card number given as a string of digits, i.e.
+/// the value the check is performed on: the number is valid if it is divisible by 10.
+fn luhn_sum(s: &str) -> Result<u32, LuhnError> {
+    weighted_sum(s, &STANDARD_DOUBLE_MAP)
+}
+
+/// Checks a card number given as a string of digits.
+fn luhn_check_str(s: &str) -> Result<bool, LuhnError> {
+    Ok(luhn_sum(s)?.is_multiple_of(10))
+}
+
+/// Like `luhn_check_str`, but every doubled digit `d` contributes `double_map[d]` to the sum
+/// instead. `STANDARD_DOUBLE_MAP` reproduces the standard algorithm.
+fn luhn_check_with(s: &str, double_map: &[u8; 10]) -> Result<bool, LuhnError> {
+    Ok(weighted_sum(s, double_map)?.is_multiple_of(10))
+}
+
+/// Formats a card number into groups of `group` digits separated by `sep`, e.g. with groups of 4
+/// and a space: `"1234 5678 9012 3456"`. The last group may be shorter.
+fn format_card(s: &str, group: usize, sep: char) -> Result<String, LuhnError> {
+    if group == 0 {
+        return Err(LuhnError::ZeroGroupSize);
+    }
+
+    parse_digits(s)?;
+
+    let groups: Vec<&str> = s
+        .as_bytes()
+        .chunks(group)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap())
+        .collect();
 
-    *last_num == ((10 - (sum % 10)) % 10)
+    Ok(groups.join(&sep.to_string()))
 }
 
 /// Below you can find a set of unit tests.
 #[cfg(test)]
 mod tests {
-    use super::luhn_algorithm;
+    use super::{
+        format_card, luhn_algorithm, luhn_check_str, luhn_check_u128, luhn_check_with, luhn_sum,
+        LuhnError, STANDARD_DOUBLE_MAP,
+    };
 
     #[test]
     fn luhn_zero() {
@@ -59,4 +183,94 @@ mod tests {
         assert!(!luhn_algorithm(17893729977));
         assert!(!luhn_algorithm(123456));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn luhn_max_u64() {
+        assert!(!luhn_algorithm(u64::MAX));
+        assert!(luhn_algorithm(18446744073709551611));
+    }
+
+    #[test]
+    fn format_card_groups() {
+        assert_eq!(
+            format_card("4539578763621486", 4, ' '),
+            Ok("4539 5787 6362 1486".to_string())
+        );
+        assert_eq!(format_card("79927398713", 4, '-'), Ok("7992-7398-713".to_string()));
+        assert_eq!(format_card("1234", 4, ' '), Ok("1234".to_string()));
+    }
+
+    #[test]
+    fn format_card_invalid() {
+        assert_eq!(format_card("", 4, ' '), Err(LuhnError::Empty));
+        assert_eq!(
+            format_card("4539 5787", 4, ' '),
+            Err(LuhnError::InvalidDigit { location: 4, character: ' ' })
+        );
+        assert_eq!(format_card("4539", 0, ' '), Err(LuhnError::ZeroGroupSize));
+    }
+
+    #[test]
+    fn luhn_u128_matches_u64() {
+        for n in [0, 10, 18, 123456, 17893729974, 17893729975, 79927398713, u64::MAX] {
+            assert_eq!(luhn_check_u128(n as u128), luhn_algorithm(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn luhn_u128_19_digits() {
+        assert!(luhn_check_u128(6011000990139421237));
+        assert!(!luhn_check_u128(6011000990139421238));
+        assert!(!luhn_check_u128(6011000990139421273));
+    }
+
+    #[test]
+    fn luhn_u128_wide() {
+        assert!(luhn_check_u128(340211111111111111111111111111111111114));
+        assert!(!luhn_check_u128(u128::MAX));
+    }
+
+    #[test]
+    fn luhn_str() {
+        assert_eq!(luhn_check_str("79927398713"), Ok(true));
+        assert_eq!(luhn_check_str("0079927398713"), Ok(true));
+        assert_eq!(luhn_check_str("79927398710"), Ok(false));
+        assert_eq!(luhn_check_str(""), Err(LuhnError::Empty));
+        assert_eq!(
+            luhn_check_str("7992a"),
+            Err(LuhnError::InvalidDigit { location: 4, character: 'a' })
+        );
+    }
+
+    #[test]
+    fn luhn_with_standard_map() {
+        for s in ["0", "18", "10", "79927398713", "17893729975", "4539578763621486", "123456"] {
+            assert_eq!(luhn_check_with(s, &STANDARD_DOUBLE_MAP), luhn_check_str(s), "{s}");
+        }
+        assert_eq!(luhn_check_with("", &STANDARD_DOUBLE_MAP), Err(LuhnError::Empty));
+    }
+
+    #[test]
+    fn luhn_with_custom_map() {
+        // Doubled digits contribute nothing, so only the other digits are summed.
+        let ignore_doubled = [0; 10];
+        assert_eq!(luhn_check_with("90", &ignore_doubled), Ok(true));
+        assert_eq!(luhn_check_with("19", &ignore_doubled), Ok(false));
+
+        // Doubled digits contribute `2 * d` without subtracting 9.
+        let plain_double = [0, 2, 4, 6, 8, 10, 12, 14, 16, 18];
+        assert_eq!(luhn_check_with("76", &plain_double), Ok(true));
+        assert_eq!(luhn_check_str("76"), Ok(false));
+    }
+
+    #[test]
+    fn luhn_sum_of_known_number() {
+        // Weighted digits of 79927398713 from the right: 3, 2, 7, 7, 9, 6, 7, 4, 9, 9, 7.
+        assert_eq!(luhn_sum("79927398713"), Ok(70));
+        assert!(luhn_sum("79927398713").unwrap().is_multiple_of(10));
+        assert_eq!(luhn_sum("79927398710"), Ok(67));
+        assert!(!luhn_sum("79927398710").unwrap().is_multiple_of(10));
+        assert_eq!(luhn_sum("0"), Ok(0));
+        assert_eq!(luhn_sum(""), Err(LuhnError::Empty));
+    }
+}
@@ -0,0 +1,141 @@
+//! Run this file with `cargo test --test matrix_chain_multiplication`.
+
+//! TODO: Find the cheapest way to multiply a chain of matrices, see
+//! [matrix chain multiplication](https://en.wikipedia.org/wiki/Matrix_chain_multiplication).
+//!
+//! The `i`-th matrix of the chain has `dims[i]` rows and `dims[i + 1]` columns, so `dims` has one
+//! element more than there are matrices. Multiplying a `p × q` matrix by a `q × r` matrix takes
+//! `p · q · r` scalar multiplications. Matrix multiplication is associative, so the chain can be
+//! multiplied in any order, but the cost differs a lot between the orders.
+//!
+//! - `min_multiplications`: the smallest number of scalar multiplications needed to compute the
+//!   product of the whole chain.
+//! - `optimal_order`: the order achieving that minimum as a fully parenthesized string, naming
+//!   the matrices `A`, `B`, `C`, ... (at most 26 of them), e.g. `((A(BC))D)`. A single matrix is
+//!   not parenthesized.
+//!
+//! An empty chain (`dims` with fewer than two elements) costs nothing and has an empty order.
+//!
+//! Hint: let `cost[i][j]` be the minimum cost of multiplying matrices `i..=j`. The last
+//! multiplication splits the chain at some `k` into `i..=k` and `k + 1..=j`, so `cost[i][j]` is the
+//! minimum over all `k` of `cost[i][k] + cost[k + 1][j] + dims[i] · dims[k + 1] · dims[j + 1]`.
+//! Remember the best `k` to reconstruct the order.
+
+/// Returns the tables `cost` and `split`, where `cost[i][j]` is the minimum cost of multiplying
+/// matrices `i..=j` and `split[i][j]` the last matrix of the left factor in the optimal order.
+fn chain_tables(dims: &[usize]) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    let n = dims.len().saturating_sub(1);
+    let mut cost = vec![vec![0; n]; n];
+    let mut split = vec![vec![0; n]; n];
+
+    for len in 2..=n {
+        for i in 0..=n - len {
+            let j = i + len - 1;
+            let (best_cost, best_split) = (i..j)
+                .map(|k| (cost[i][k] + cost[k + 1][j] + dims[i] * dims[k + 1] * dims[j + 1], k))
+                .min()
+                .unwrap();
+            cost[i][j] = best_cost;
+            split[i][j] = best_split;
+        }
+    }
+
+    (cost, split)
+}
+
+fn min_multiplications(dims: &[usize]) -> usize {
+    let (cost, _) = chain_tables(dims);
+    cost.first().and_then(|row| row.last()).copied().unwrap_or(0)
+}
+
+fn optimal_order(dims: &[usize]) -> String {
+    fn write_order(split: &[Vec<usize>], i: usize, j: usize, order: &mut String) {
+        if i == j {
+            order.push((b'A' + i as u8) as char);
+            return;
+        }
+        order.push('(');
+        write_order(split, i, split[i][j], order);
+        write_order(split, split[i][j] + 1, j, order);
+        order.push(')');
+    }
+
+    let n = dims.len().saturating_sub(1);
+    assert!(n <= 26, "at most 26 matrices can be named");
+
+    let mut order = String::new();
+    if n > 0 {
+        let (_, split) = chain_tables(dims);
+        write_order(&split, 0, n - 1, &mut order);
+    }
+    order
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{min_multiplications, optimal_order};
+
+    /// Computes the cost of multiplying the chain in the given fully parenthesized `order`.
+    fn order_cost(dims: &[usize], order: &str) -> usize {
+        fn parse(dims: &[usize], chars: &mut std::str::Chars) -> (usize, usize, usize) {
+            match chars.next().unwrap() {
+                '(' => {
+                    let (left_cost, rows, inner) = parse(dims, chars);
+                    let (right_cost, _, columns) = parse(dims, chars);
+                    assert_eq!(chars.next(), Some(')'));
+                    (left_cost + right_cost + rows * inner * columns, rows, columns)
+                }
+                name => {
+                    let i = (name as u8 - b'A') as usize;
+                    (0, dims[i], dims[i + 1])
+                }
+            }
+        }
+        parse(dims, &mut order.chars()).0
+    }
+
+    #[test]
+    fn classic_example() {
+        let dims = [40, 20, 30, 10, 30];
+        assert_eq!(min_multiplications(&dims), 26000);
+        assert_eq!(optimal_order(&dims), "((A(BC))D)");
+    }
+
+    #[test]
+    fn textbook_example() {
+        let dims = [30, 35, 15, 5, 10, 20, 25];
+        assert_eq!(min_multiplications(&dims), 15125);
+        assert_eq!(optimal_order(&dims), "((A(BC))((DE)F))");
+    }
+
+    #[test]
+    fn two_matrices() {
+        assert_eq!(min_multiplications(&[10, 20, 30]), 6000);
+        assert_eq!(optimal_order(&[10, 20, 30]), "(AB)");
+    }
+
+    #[test]
+    fn trivial_chains() {
+        assert_eq!(min_multiplications(&[]), 0);
+        assert_eq!(optimal_order(&[]), "");
+        assert_eq!(min_multiplications(&[5]), 0);
+        assert_eq!(optimal_order(&[5]), "");
+        assert_eq!(min_multiplications(&[5, 7]), 0);
+        assert_eq!(optimal_order(&[5, 7]), "A");
+    }
+
+    #[test]
+    fn order_achieves_minimum() {
+        let chains: [&[usize]; 4] = [
+            &[10, 20, 30, 40, 30],
+            &[1, 2, 3, 4, 3],
+            &[5, 10, 3, 12, 5, 50, 6],
+            &[2, 40, 2, 40, 2, 40, 2, 40, 2],
+        ];
+        for dims in chains {
+            let order = optimal_order(dims);
+            assert_eq!(order_cost(dims, &order), min_multiplications(dims), "{order}");
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Run this file with `cargo test --test max_subarray`.
+
+//! TODO: Find the non-empty contiguous subarray with the largest sum.
+//!
+//! - `max_subarray_sum`: returns the largest sum, or `None` for an empty slice.
+//! - `max_subarray`: returns the subarray itself. If several subarrays have the largest sum,
+//!   return the one that starts first, and among those the shortest one.
+//!
+//! If every value is negative, the best subarray is the single least negative value.
+//!
+//! Use [Kadane's algorithm](https://en.wikipedia.org/wiki/Maximum_subarray_problem#Kadane's_algorithm),
+//! which solves the problem in O(n) time: for every position, the best subarray ending there
+//! either extends the best subarray ending at the previous position, or starts anew.
+
+fn max_subarray_sum(values: &[i64]) -> Option<i64> {
+    let (&first, rest) = values.split_first()?;
+    let mut best = first;
+    let mut current = first;
+
+    for &value in rest {
+        current = value.max(current + value);
+        best = best.max(current);
+    }
+
+    Some(best)
+}
+
+fn max_subarray(values: &[i64]) -> Option<&[i64]> {
+    let (&first, rest) = values.split_first()?;
+    let (mut best_start, mut best_end, mut best) = (0, 1, first);
+    let (mut current_start, mut current) = (0, first);
+
+    for (offset, &value) in rest.iter().enumerate() {
+        let index = offset + 1;
+        // A prefix summing to zero is kept, so that the subarray starts as early as possible.
+        if current < 0 {
+            current_start = index;
+            current = value;
+        } else {
+            current += value;
+        }
+
+        // Only a strictly larger sum replaces the best subarray, which keeps the earliest start
+        // and, for that start, the shortest subarray.
+        if current > best {
+            best_start = current_start;
+            best_end = index + 1;
+            best = current;
+        }
+    }
+
+    Some(&values[best_start..best_end])
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{max_subarray, max_subarray_sum};
+
+    #[test]
+    fn empty() {
+        assert_eq!(max_subarray_sum(&[]), None);
+        assert_eq!(max_subarray(&[]), None);
+    }
+
+    #[test]
+    fn single_value() {
+        assert_eq!(max_subarray_sum(&[5]), Some(5));
+        assert_eq!(max_subarray(&[-5]), Some(&[-5][..]));
+    }
+
+    #[test]
+    fn mixed_values() {
+        let values = [-2, 1, -3, 4, -1, 2, 1, -5, 4];
+        assert_eq!(max_subarray_sum(&values), Some(6));
+        assert_eq!(max_subarray(&values), Some(&[4, -1, 2, 1][..]));
+    }
+
+    #[test]
+    fn all_positive() {
+        let values = [1, 2, 3];
+        assert_eq!(max_subarray_sum(&values), Some(6));
+        assert_eq!(max_subarray(&values), Some(&values[..]));
+    }
+
+    #[test]
+    fn all_negative() {
+        let values = [-8, -3, -6, -2, -5, -4];
+        assert_eq!(max_subarray_sum(&values), Some(-2));
+        assert_eq!(max_subarray(&values), Some(&[-2][..]));
+    }
+
+    #[test]
+    fn ties() {
+        // Both `[3]` subarrays sum to 3, the first one wins.
+        assert_eq!(max_subarray(&[3, -5, 3]), Some(&[3][..]));
+        // `[2, -2, 2]` and `[2]` start at the same position, the shorter one wins.
+        assert_eq!(max_subarray(&[2, -2, 2]), Some(&[2][..]));
+        // Zeros do not change the sum, so they are not included.
+        assert_eq!(max_subarray(&[0, 0, 4, 0]), Some(&[0, 0, 4][..]));
+        assert_eq!(max_subarray(&[-1, 0, -2]), Some(&[0][..]));
+    }
+
+    #[test]
+    fn subarray_sum_matches() {
+        for values in [
+            vec![5, -9, 6, -2, 3],
+            vec![-1, -1, -1],
+            vec![1, -1, 1, -1, 1],
+            vec![10, -20, 30, -5, 8, -40, 12],
+        ] {
+            let subarray = max_subarray(&values).unwrap();
+            assert_eq!(Some(subarray.iter().sum()), max_subarray_sum(&values));
+        }
+    }
+}
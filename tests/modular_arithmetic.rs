@@ -0,0 +1,204 @@
+//! Run this file with `cargo test --test modular_arithmetic`.
+
+//! TODO: Implement a type `Modular` for [modular arithmetic](https://en.wikipedia.org/wiki/Modular_arithmetic),
+//! i.e. computing with the remainders modulo `modulus`.
+//!
+//! - `new`: creates the remainder of `value` modulo `modulus`, which must not be zero.
+//! - `+`, `-` and `*`: compute modulo the shared modulus. Combining numbers with different moduli
+//!   is a bug in the caller, panic in that case.
+//! - `pow`: raises the number to the power `exp` by
+//!   [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring),
+//!   which needs only O(log exp) multiplications.
+//! - `inverse`: the number `y` with `x * y = 1`. If the modulus `p` is prime, by
+//!   [Fermat's little theorem](https://en.wikipedia.org/wiki/Fermat%27s_little_theorem)
+//!   `x^(p - 1) = 1` for all `x != 0`, so the inverse is `x^(p - 2)`. Return `None` if there is
+//!   no inverse, e.g. for zero.
+//!
+//! Make sure that nothing overflows, even for moduli close to `u64::MAX`.
+
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Eq, PartialEq)]
+enum ModularError {
+    ZeroModulus,
+}
+
+impl Display for ModularError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for ModularError {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Modular {
+    value: u64,
+    modulus: u64,
+}
+
+impl Modular {
+    fn new(value: u64, modulus: u64) -> Result<Self, ModularError> {
+        if modulus == 0 {
+            return Err(ModularError::ZeroModulus);
+        }
+        Ok(Self {
+            value: value % modulus,
+            modulus,
+        })
+    }
+
+    /// The remainder of `value` modulo the same modulus. Takes a `u128`, so that sums and
+    /// products of two remainders fit.
+    fn with_value(&self, value: u128) -> Self {
+        Self {
+            value: (value % self.modulus as u128) as u64,
+            modulus: self.modulus,
+        }
+    }
+
+    fn pow(&self, mut exp: u64) -> Self {
+        let mut result = self.with_value(1);
+        let mut base = *self;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp /= 2;
+        }
+        result
+    }
+
+    /// Returns the inverse computed by Fermat's little theorem, which is only correct if the
+    /// modulus is prime. The result is checked, so other moduli return `None` instead of a wrong
+    /// inverse.
+    fn inverse(&self) -> Option<Self> {
+        if self.value == 0 || self.modulus < 2 {
+            return None;
+        }
+        let candidate = self.pow(self.modulus - 2);
+        ((candidate * *self).value == 1).then_some(candidate)
+    }
+
+    fn assert_same_modulus(&self, other: &Self) {
+        assert_eq!(self.modulus, other.modulus, "moduli must match");
+    }
+}
+
+impl Add for Modular {
+    type Output = Modular;
+
+    fn add(self, other: Modular) -> Modular {
+        self.assert_same_modulus(&other);
+        self.with_value(self.value as u128 + other.value as u128)
+    }
+}
+
+impl Sub for Modular {
+    type Output = Modular;
+
+    fn sub(self, other: Modular) -> Modular {
+        self.assert_same_modulus(&other);
+        self.with_value(self.value as u128 + self.modulus as u128 - other.value as u128)
+    }
+}
+
+impl Mul for Modular {
+    type Output = Modular;
+
+    fn mul(self, other: Modular) -> Modular {
+        self.assert_same_modulus(&other);
+        self.with_value(self.value as u128 * other.value as u128)
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{Modular, ModularError};
+
+    const P: u64 = 1_000_000_007;
+
+    fn m(value: u64) -> Modular {
+        Modular::new(value, P).unwrap()
+    }
+
+    #[test]
+    fn new_reduces_value() {
+        assert_eq!(Modular::new(17, 5), Ok(Modular { value: 2, modulus: 5 }));
+        assert_eq!(Modular::new(3, 0), Err(ModularError::ZeroModulus));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let seven = Modular::new(7, 11).unwrap();
+        let five = Modular::new(5, 11).unwrap();
+        assert_eq!((seven + five).value, 1);
+        assert_eq!((five - seven).value, 9);
+        assert_eq!((seven - five).value, 2);
+        assert_eq!((seven * five).value, 2);
+    }
+
+    #[test]
+    fn no_overflow_with_large_modulus() {
+        let modulus = u64::MAX - 58; // The largest prime below 2^64.
+        let a = Modular::new(u64::MAX - 60, modulus).unwrap();
+        let b = Modular::new(u64::MAX - 59, modulus).unwrap();
+        assert_eq!((a + b).value, modulus - 3);
+        assert_eq!((a * b).value, 2);
+        assert_eq!((a - b).value, modulus - 1);
+        assert_eq!((a * a.inverse().unwrap()).value, 1);
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(Modular::new(2, 1000).unwrap().pow(10).value, 24);
+        assert_eq!(m(12345).pow(0).value, 1);
+        assert_eq!(m(0).pow(5).value, 0);
+        assert_eq!(m(3).pow(P - 1).value, 1);
+        assert_eq!(Modular::new(5, 1).unwrap().pow(0).value, 0);
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(Modular::new(3, 7).unwrap().inverse(), Modular::new(5, 7).ok());
+        assert_eq!(m(0).inverse(), None);
+        assert_eq!(m(1).inverse(), Some(m(1)));
+        // 4 has no inverse modulo 8.
+        assert_eq!(Modular::new(4, 8).unwrap().inverse(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "moduli must match")]
+    fn different_moduli() {
+        let _ = Modular::new(1, 5).unwrap() + Modular::new(1, 7).unwrap();
+    }
+
+    #[test]
+    fn identities_modulo_prime() {
+        let values = [0, 1, 2, 3, 1234, 999_999, P - 1, P - 2, 500_000_004];
+        for a in values.map(m) {
+            assert_eq!(a + m(0), a);
+            assert_eq!(a * m(1), a);
+            assert_eq!(a - a, m(0));
+            assert_eq!(a.pow(P), a);
+            if a != m(0) {
+                assert_eq!(a * a.inverse().unwrap(), m(1));
+            }
+
+            for b in values.map(m) {
+                assert_eq!(a + b, b + a);
+                assert_eq!(a * b, b * a);
+                assert_eq!((a - b) + b, a);
+                assert_eq!((a + b).pow(2), a * a + m(2) * a * b + b * b);
+                assert_eq!((a * b).pow(7), a.pow(7) * b.pow(7));
+                for c in [m(5), m(P - 7)] {
+                    assert_eq!(a * (b + c), a * b + a * c);
+                }
+            }
+        }
+    }
+}
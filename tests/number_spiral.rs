@@ -0,0 +1,164 @@
+//! Run this file with `cargo test --test number_spiral`.
+
+//! TODO: Implement a function called `number_spiral`, which returns a `size` x `size` matrix (as a
+//! list of rows) filled with the numbers `1..=size * size` in a clockwise spiral that starts in
+//! the center and winds outward.
+//!
+//! Starting from `1`, the spiral moves one step right, one step down, two steps left, two steps
+//! up, three steps right, three steps down, and so on. For example, for `size = 3`:
+//!
+//! ```text
+//! 7 8 9
+//! 6 1 2
+//! 5 4 3
+//! ```
+//!
+//! An even-sized matrix has no single center cell. In that case, `1` is placed in the upper-left
+//! cell of the central 2x2 block, e.g. for `size = 2`:
+//!
+//! ```text
+//! 1 2
+//! 4 3
+//! ```
+
+fn number_spiral(size: usize) -> Vec<Vec<u32>> {
+    let mut matrix = vec![vec![0; size]; size];
+    if size == 0 {
+        return matrix;
+    }
+
+    // Right, down, left, up.
+    let directions: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+    let last = (size * size) as u32;
+    let center = (size - 1) / 2;
+    let (mut row, mut col) = (center as isize, center as isize);
+    let mut value = 1;
+    let mut step_length = 1;
+    let mut direction = 0;
+
+    matrix[center][center] = value;
+    while value < last {
+        // Every step length is used for two consecutive directions.
+        for _ in 0..2 {
+            let (d_row, d_col) = directions[direction];
+            for _ in 0..step_length {
+                if value == last {
+                    return matrix;
+                }
+                row += d_row;
+                col += d_col;
+                value += 1;
+                matrix[row as usize][col as usize] = value;
+            }
+            direction = (direction + 1) % 4;
+        }
+        step_length += 1;
+    }
+
+    matrix
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::number_spiral;
+
+    /// Checks that every number appears exactly once and that consecutive numbers are neighbours.
+    fn check_spiral(size: usize) {
+        let matrix = number_spiral(size);
+        assert_eq!(matrix.len(), size);
+
+        let mut positions = vec![None; size * size + 1];
+        for (row, values) in matrix.iter().enumerate() {
+            assert_eq!(values.len(), size);
+            for (col, &value) in values.iter().enumerate() {
+                assert!(value >= 1 && value as usize <= size * size);
+                assert!(positions[value as usize].is_none(), "{value} appears twice");
+                positions[value as usize] = Some((row, col));
+            }
+        }
+
+        for value in 2..=size * size {
+            let (r1, c1) = positions[value - 1].unwrap();
+            let (r2, c2) = positions[value].unwrap();
+            let distance = r1.abs_diff(r2) + c1.abs_diff(c2);
+            assert_eq!(distance, 1, "{} and {value} are not adjacent", value - 1);
+        }
+
+        assert_eq!(positions[1], Some(((size - 1) / 2, (size - 1) / 2)));
+    }
+
+    #[test]
+    fn spiral_size_0() {
+        assert!(number_spiral(0).is_empty());
+    }
+
+    #[test]
+    fn spiral_size_1() {
+        assert_eq!(number_spiral(1), vec![vec![1]]);
+    }
+
+    #[test]
+    fn spiral_size_2() {
+        assert_eq!(number_spiral(2), vec![vec![1, 2], vec![4, 3]]);
+    }
+
+    #[test]
+    fn spiral_size_3() {
+        assert_eq!(
+            number_spiral(3),
+            vec![vec![7, 8, 9], vec![6, 1, 2], vec![5, 4, 3]]
+        );
+    }
+
+    #[test]
+    fn spiral_size_4() {
+        assert_eq!(
+            number_spiral(4),
+            vec![
+                vec![7, 8, 9, 10],
+                vec![6, 1, 2, 11],
+                vec![5, 4, 3, 12],
+                vec![16, 15, 14, 13],
+            ]
+        );
+    }
+
+    #[test]
+    fn spiral_size_5() {
+        assert_eq!(
+            number_spiral(5),
+            vec![
+                vec![21, 22, 23, 24, 25],
+                vec![20, 7, 8, 9, 10],
+                vec![19, 6, 1, 2, 11],
+                vec![18, 5, 4, 3, 12],
+                vec![17, 16, 15, 14, 13],
+            ]
+        );
+    }
+
+    #[test]
+    fn spiral_size_6() {
+        let matrix = number_spiral(6);
+        assert_eq!(matrix[0], vec![21, 22, 23, 24, 25, 26]);
+        assert_eq!(matrix[5], vec![36, 35, 34, 33, 32, 31]);
+        check_spiral(6);
+    }
+
+    #[test]
+    fn spiral_size_7() {
+        let matrix = number_spiral(7);
+        assert_eq!(matrix[3][3], 1);
+        assert_eq!(matrix[0], vec![43, 44, 45, 46, 47, 48, 49]);
+        assert_eq!(matrix[6], vec![37, 36, 35, 34, 33, 32, 31]);
+        check_spiral(7);
+    }
+
+    #[test]
+    fn spiral_properties() {
+        for size in 1..=7 {
+            check_spiral(size);
+        }
+    }
+}
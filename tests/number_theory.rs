@@ -0,0 +1,147 @@
+//! Run this file with `cargo test --test number_theory`.
+
+//! TODO: Implement a few multiplicative functions from number theory.
+//!
+//! - `euler_totient`: [Euler's totient function](https://en.wikipedia.org/wiki/Euler%27s_totient_function)
+//!   φ(n), the number of integers in `1..=n` that are coprime to `n`.
+//! - `mobius`: the [Möbius function](https://en.wikipedia.org/wiki/M%C3%B6bius_function) μ(n),
+//!   which is 0 if `n` is divisible by the square of a prime, and otherwise (-1)^k, where `k` is
+//!   the number of distinct prime factors of `n`.
+//! - `sum_totient`: the sum of φ(1), φ(2), ..., φ(n).
+//!
+//! Both φ and μ can be computed from the prime factorization of `n`, e.g. φ(n) is `n` multiplied
+//! by (1 - 1/p) for every distinct prime factor `p`. For `sum_totient`, computing every φ(k)
+//! separately is slow; instead, use a sieve: start with φ(k) = k for all `k` and, for every prime
+//! `p`, multiply all of its multiples by (1 - 1/p).
+//!
+//! φ(0) and μ(0) are not defined, return 0 for them.
+
+/// Returns the distinct prime factors of `n` and their exponents, using trial division.
+fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while p <= n / p {
+        let mut exponent = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((p, exponent));
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+fn euler_totient(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n)
+        .iter()
+        .fold(n, |result, &(p, _)| result / p * (p - 1))
+}
+
+fn mobius(n: u64) -> i32 {
+    if n == 0 {
+        return 0;
+    }
+
+    let factors = factorize(n);
+    if factors.iter().any(|&(_, exponent)| exponent > 1) {
+        return 0;
+    }
+    if factors.len().is_multiple_of(2) { 1 } else { -1 }
+}
+
+fn sum_totient(n: u64) -> u64 {
+    let n = n as usize;
+    let mut phi: Vec<u64> = (0..=n as u64).collect();
+
+    for p in 2..=n {
+        // `phi[p]` is still `p` exactly when no smaller prime divides `p`.
+        if phi[p] == p as u64 {
+            for multiple in (p..=n).step_by(p) {
+                phi[multiple] = phi[multiple] / p as u64 * (p as u64 - 1);
+            }
+        }
+    }
+
+    phi.iter().sum()
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{euler_totient, mobius, sum_totient};
+
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 { a } else { gcd(b, a % b) }
+    }
+
+    #[test]
+    fn totient_known_values() {
+        assert_eq!(euler_totient(1), 1);
+        assert_eq!(euler_totient(9), 6);
+        assert_eq!(euler_totient(10), 4);
+        assert_eq!(euler_totient(36), 12);
+        assert_eq!(euler_totient(1 << 40), 1 << 39);
+        assert_eq!(euler_totient(0), 0);
+    }
+
+    #[test]
+    fn totient_of_primes() {
+        for p in [2, 3, 5, 7, 11, 13, 97, 7919, 1_000_000_007] {
+            assert_eq!(euler_totient(p), p - 1);
+        }
+    }
+
+    #[test]
+    fn totient_counts_coprimes() {
+        for n in 1..200 {
+            let coprimes = (1..=n).filter(|&k| gcd(n, k) == 1).count() as u64;
+            assert_eq!(euler_totient(n), coprimes, "{n}");
+        }
+    }
+
+    #[test]
+    fn mobius_known_values() {
+        assert_eq!(mobius(1), 1);
+        assert_eq!(mobius(2), -1);
+        assert_eq!(mobius(4), 0);
+        assert_eq!(mobius(6), 1);
+        assert_eq!(mobius(30), -1);
+        assert_eq!(mobius(12), 0);
+        assert_eq!(mobius(210), 1);
+        assert_eq!(mobius(0), 0);
+    }
+
+    #[test]
+    fn mobius_sums_to_zero_over_divisors() {
+        // The sum of μ(d) over all divisors d of n is 0 for every n > 1.
+        for n in 2..200u64 {
+            let sum: i32 = (1..=n).filter(|d| n % d == 0).map(mobius).sum();
+            assert_eq!(sum, 0, "{n}");
+        }
+    }
+
+    #[test]
+    fn sum_totient_values() {
+        assert_eq!(sum_totient(0), 0);
+        assert_eq!(sum_totient(1), 1);
+        assert_eq!(sum_totient(10), 32);
+        assert_eq!(sum_totient(100), 3044);
+    }
+
+    #[test]
+    fn sum_totient_matches_direct_sum() {
+        for n in [1, 2, 17, 64, 500, 1234] {
+            let expected: u64 = (1..=n).map(euler_totient).sum();
+            assert_eq!(sum_totient(n), expected, "{n}");
+        }
+    }
+}
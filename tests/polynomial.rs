@@ -0,0 +1,160 @@
+//! Run this file with `cargo test --test polynomial`.
+
+//! TODO: Implement a `Polynomial` type with real coefficients, where `coefficients[i]` is the
+//! coefficient of `x^i`. For example, `[1.0, 0.0, 3.0]` is `1 + 3x²`.
+//!
+//! - `new`: creates a polynomial, dropping trailing zero coefficients so that every polynomial
+//!   has exactly one representation. The zero polynomial has no coefficients at all.
+//! - `evaluate`: computes the value at `x` using [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+//!   i.e. `a0 + x * (a1 + x * (a2 + ...))`, which needs no powers of `x`.
+//! - `+` and `*`: add and multiply two polynomials.
+//! - `derivative`: the derivative, e.g. `3x²` for `x³`.
+//! - `from_roots`: the polynomial `(x - r1) * (x - r2) * ...` with the given roots.
+
+use std::ops::{Add, Mul};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    fn new(mut coefficients: Vec<f64>) -> Self {
+        while coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+
+    fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |result, &coefficient| result * x + coefficient)
+    }
+
+    fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(power, &coefficient)| coefficient * power as f64)
+                .collect(),
+        )
+    }
+
+    fn from_roots(roots: &[f64]) -> Polynomial {
+        roots.iter().fold(Polynomial::new(vec![1.0]), |product, &root| {
+            product * Polynomial::new(vec![-root, 1.0])
+        })
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: Polynomial) -> Polynomial {
+        let len = self.coefficients.len().max(other.coefficients.len());
+        let coefficient = |p: &Polynomial, i: usize| p.coefficients.get(i).copied().unwrap_or(0.0);
+
+        Polynomial::new(
+            (0..len)
+                .map(|i| coefficient(&self, i) + coefficient(&other, i))
+                .collect(),
+        )
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: Polynomial) -> Polynomial {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Polynomial::new(vec![]);
+        }
+
+        let mut product = vec![0.0; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                product[i + j] += a * b;
+            }
+        }
+        Polynomial::new(product)
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::Polynomial;
+
+    fn poly(coefficients: &[f64]) -> Polynomial {
+        Polynomial::new(coefficients.to_vec())
+    }
+
+    #[test]
+    fn trailing_zeros_are_dropped() {
+        assert_eq!(poly(&[1.0, 2.0, 0.0, 0.0]), poly(&[1.0, 2.0]));
+        assert!(poly(&[0.0, 0.0]).coefficients.is_empty());
+    }
+
+    #[test]
+    fn evaluate() {
+        // 2 - 3x + x²
+        let p = poly(&[2.0, -3.0, 1.0]);
+        assert_eq!(p.evaluate(0.0), 2.0);
+        assert_eq!(p.evaluate(1.0), 0.0);
+        assert_eq!(p.evaluate(2.0), 0.0);
+        assert_eq!(p.evaluate(5.0), 12.0);
+        assert_eq!(poly(&[]).evaluate(3.0), 0.0);
+        assert_eq!(poly(&[7.0]).evaluate(-100.0), 7.0);
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(poly(&[1.0, 2.0]) + poly(&[3.0, 4.0, 5.0]), poly(&[4.0, 6.0, 5.0]));
+        assert_eq!(poly(&[1.0, 2.0, 3.0]) + poly(&[0.0, 0.0, -3.0]), poly(&[1.0, 2.0]));
+        assert_eq!(poly(&[1.0]) + poly(&[]), poly(&[1.0]));
+    }
+
+    #[test]
+    fn product_of_linears() {
+        // (x + 1)(2x - 3) = 2x² - x - 3
+        assert_eq!(poly(&[1.0, 1.0]) * poly(&[-3.0, 2.0]), poly(&[-3.0, -1.0, 2.0]));
+        assert_eq!(poly(&[1.0, 1.0]) * poly(&[]), poly(&[]));
+    }
+
+    #[test]
+    fn derivative_of_cube() {
+        assert_eq!(poly(&[0.0, 0.0, 0.0, 1.0]).derivative(), poly(&[0.0, 0.0, 3.0]));
+        assert_eq!(poly(&[5.0, 2.0, 4.0]).derivative(), poly(&[2.0, 8.0]));
+        assert_eq!(poly(&[5.0]).derivative(), poly(&[]));
+        assert_eq!(poly(&[]).derivative(), poly(&[]));
+    }
+
+    #[test]
+    fn from_roots() {
+        assert_eq!(Polynomial::from_roots(&[]), poly(&[1.0]));
+        assert_eq!(Polynomial::from_roots(&[1.0, 2.0]), poly(&[2.0, -3.0, 1.0]));
+
+        let roots = [-2.0, 0.5, 3.0, 3.0];
+        let p = Polynomial::from_roots(&roots);
+        assert_eq!(p.coefficients.len(), 5);
+        for root in roots {
+            assert_eq!(p.evaluate(root), 0.0);
+        }
+        // A double root is also a root of the derivative.
+        assert_eq!(p.derivative().evaluate(3.0), 0.0);
+    }
+
+    #[test]
+    fn product_rule() {
+        let f = poly(&[1.0, -2.0, 3.0]);
+        let g = poly(&[0.0, 4.0, 0.0, 1.0]);
+        assert_eq!(
+            (f.clone() * g.clone()).derivative(),
+            f.derivative() * g.clone() + f * g.derivative()
+        );
+    }
+}
@@ -21,7 +21,45 @@
 //! numbers in the range :) Otherwise tests will explode.
 
 use std::{cmp::max, cmp::min};
+use std::error::Error;
+use std::fmt::Display;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[derive(Debug, Eq, PartialEq)]
+enum RangeError {
+    NotMonotonic { start: u64, end: u64 },
+    /// The range `[start, end]` is not contained in the universe it was expected to lie in.
+    OutsideUniverse { start: u64, end: u64 },
+    /// Two ranges that were required to overlap have no integer in common.
+    Disjoint,
+    /// The range would end at `u64::MAX`, which cannot be represented as `end` is exclusive.
+    EndTooLarge,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
 
+impl Error for RangeError {}
+
+/// Returned when a range holds more integers than the caller allowed to materialize.
+#[derive(Debug, Eq, PartialEq)]
+struct RangeTooLarge {
+    len: u64,
+    max: usize,
+}
+
+impl Display for RangeTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for RangeTooLarge {}
 
 #[derive(Debug, Copy, Clone)]
 struct Range1D {
@@ -42,22 +80,62 @@ impl Range1D {
         }
     }
 
-    fn len(self) -> usize {
-        (self.end - self.start) as usize
+    /// Constructs the range `[value, value]`, which contains only `value`. Fails for `u64::MAX`,
+    /// like every range ending there.
+    fn unit(value: u64) -> Result<Range1D, RangeError> {
+        let end = value.checked_add(1).ok_or(RangeError::EndTooLarge)?;
+        Ok(Self { start: value, end })
+    }
+
+    /// Returns the number of integers in the range. This is a `u64` because a range can hold more
+    /// integers than `usize` can count on 32-bit targets; callers that need a `usize` (e.g. for
+    /// slice indexing) must convert it with `usize::try_from` themselves.
+    fn len(self) -> u64 {
+        self.end - self.start
     }
 
     fn iter(self) -> impl Iterator<Item = u64> {
         (self.start..self.end).into_iter()
     }
 
+    /// Materializes the integers of the range, as long as there are at most `max` of them.
+    fn densify(&self, max: usize) -> Result<Box<[u64]>, RangeTooLarge> {
+        let len = self.len();
+        if len > max as u64 {
+            return Err(RangeTooLarge { len, max });
+        }
+
+        Ok(self.iter().collect())
+    }
+
     fn start(&self) -> u64 {
         self.start
     }
 
+    #[deprecated(note = "ambiguous with the exclusive end stored internally, use `end_inclusive`")]
     fn end(&self) -> u64 {
+        self.end_inclusive()
+    }
+
+    fn start_inclusive(&self) -> u64 {
+        self.start
+    }
+
+    /// The range stores its end as `end + 1`, this undoes that offset.
+    fn end_inclusive(&self) -> u64 {
         self.end - 1
     }
 
+    /// The smallest integer of the range, same as `start_inclusive`.
+    fn first(&self) -> u64 {
+        self.start_inclusive()
+    }
+
+    /// The largest integer of the range, same as `end_inclusive`.
+    fn last(&self) -> u64 {
+        self.end_inclusive()
+    }
+
     fn intersect(self, other: Self) -> Option<Range1D> {
         let max_start = max(self.start, other.start);
         let min_end = min(self.end, other.end);
@@ -73,8 +151,188 @@ impl Range1D {
         }
     }
 
+    /// Like `intersect`, but treats an empty intersection as an error, so that it composes with
+    /// `?`.
+    fn intersect_required(self, other: Self) -> Result<Range1D, RangeError> {
+        self.intersect(other).ok_or(RangeError::Disjoint)
+    }
+
+    /// Number of integers contained in both ranges, without building the intersection.
+    fn overlap_len(&self, other: &Range1D) -> u64 {
+        let max_start = max(self.start, other.start);
+        let min_end = min(self.end, other.end);
+
+        min_end.saturating_sub(max_start)
+    }
+
     fn contains(&self, item: u64) -> bool {
-        item >= self.start && item < self.end
+        (self.start_inclusive()..=self.end_inclusive()).contains(&item)
+    }
+
+    /// Returns the `i`-th integer of the range, counting from zero.
+    fn nth(&self, i: u64) -> Option<u64> {
+        (i < self.len()).then(|| self.start + i)
+    }
+
+    /// Returns the `i`-th integer of the range counting from the end, so `0` is the last one.
+    fn nth_from_end(&self, i: u64) -> Option<u64> {
+        (i < self.len()).then(|| self.end_inclusive() - i)
+    }
+
+    /// Returns the point of the range closest to `point`.
+    fn clamp(&self, point: u64) -> u64 {
+        point.clamp(self.start_inclusive(), self.end_inclusive())
+    }
+
+    /// Extends the range just enough to contain `point`, keeping it as is if it already does.
+    /// Fails for `point == u64::MAX`, like every range ending there.
+    fn grow_to_include(self, point: u64) -> Result<Range1D, RangeError> {
+        let end = point.checked_add(1).ok_or(RangeError::EndTooLarge)?;
+        Ok(Self {
+            start: self.start.min(point),
+            end: self.end.max(end),
+        })
+    }
+
+    fn clamp_iter<I: Iterator<Item = u64>>(&self, iter: I) -> impl Iterator<Item = u64> {
+        let range = *self;
+        iter.map(move |point| range.clamp(point))
+    }
+
+    /// Sums all integers in the range using the arithmetic series formula, in constant time.
+    fn sum(&self) -> u128 {
+        let start = self.start_inclusive() as u128;
+        let end = self.end_inclusive() as u128;
+
+        (start + end) * self.len() as u128 / 2
+    }
+
+    /// Applies `f` to both endpoints. `f` must be monotonic non-decreasing, otherwise the mapped
+    /// endpoints would no longer form a valid range.
+    fn map<F: Fn(u64) -> u64>(self, f: F) -> Result<Range1D, RangeError> {
+        let start = f(self.start_inclusive());
+        let end = f(self.end_inclusive());
+
+        if start > end {
+            return Err(RangeError::NotMonotonic { start, end });
+        }
+
+        let end = end.checked_add(1).ok_or(RangeError::EndTooLarge)?;
+        Ok(Self { start, end })
+    }
+
+    /// Splits the range into at most `n` consecutive chunks whose lengths differ by at most one.
+    /// Returns fewer chunks if the range holds fewer than `n` integers, and none if `n` is zero.
+    fn split_into(&self, n: u64) -> Vec<Range1D> {
+        let chunks = n.min(self.len());
+        if chunks == 0 {
+            return vec![];
+        }
+
+        let (size, remainder) = (self.len() / chunks, self.len() % chunks);
+        let mut start = self.start;
+        (0..chunks)
+            .map(|i| {
+                // The first `remainder` chunks take one extra integer each.
+                let len = size + u64::from(i < remainder);
+                let chunk = Self { start, end: start + len };
+                start += len;
+                chunk
+            })
+            .collect()
+    }
+
+    /// Splits the range into consecutive chunks of `size` integers, except for the last chunk,
+    /// which may be shorter. Returns no chunks if `size` is zero.
+    fn subdivide_by_size(self, size: u64) -> Vec<Range1D> {
+        if size == 0 {
+            return vec![];
+        }
+
+        let mut chunks = vec![];
+        let mut start = self.start;
+        while start < self.end {
+            let end = start.saturating_add(size).min(self.end);
+            chunks.push(Self { start, end });
+            start = end;
+        }
+        chunks
+    }
+
+    /// Returns the maximal runs of consecutive integers for which `f` holds, in order. This calls
+    /// `f` for every integer of the range, so it takes time proportional to `len`.
+    fn partition_by<F: Fn(u64) -> bool>(self, f: F) -> Vec<Range1D> {
+        let mut runs = vec![];
+        let mut run_start = None;
+
+        for point in self.iter() {
+            match (f(point), run_start) {
+                (true, None) => run_start = Some(point),
+                (false, Some(start)) => {
+                    runs.push(Self { start, end: point });
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(start) = run_start {
+            runs.push(Self { start, end: self.end });
+        }
+
+        runs
+    }
+
+    /// Iterates over the integers of the range in parallel.
+    #[cfg(feature = "rayon")]
+    fn par_iter(&self) -> impl ParallelIterator<Item = u64> {
+        (self.start..self.end).into_par_iter()
+    }
+
+    /// Iterates over the chunks produced by `split_into` in parallel. Unlike `par_iter`, this
+    /// iterator is indexed, so it supports e.g. `zip` and `enumerate`.
+    #[cfg(feature = "rayon")]
+    fn par_chunks(&self, n: u64) -> impl IndexedParallelIterator<Item = Range1D> {
+        self.split_into(n).into_par_iter()
+    }
+
+    /// Returns one flag per integer of `universe`, which is `true` for the integers contained in
+    /// this range. Meant for small universes, as the whole universe is materialized.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_bitset(&self, universe: Range1D) -> Result<Vec<bool>, RangeError> {
+        if self.start < universe.start || self.end > universe.end {
+            return Err(RangeError::OutsideUniverse {
+                start: self.start_inclusive(),
+                end: self.end_inclusive(),
+            });
+        }
+
+        Ok(universe.iter().map(|point| self.contains(point)).collect())
+    }
+
+    /// Translates the range by `delta` in the circular space `0..modulus`, e.g. the indices of a
+    /// ring buffer. A range that wraps around the end of the space is split into two ranges, and
+    /// a range holding at least `modulus` integers covers the whole space.
+    ///
+    /// Panics if `modulus` is zero.
+    fn wrapping_shift(self, delta: i64, modulus: u64) -> Vec<Range1D> {
+        assert!(modulus > 0, "modulus must not be zero");
+
+        if self.len() >= modulus {
+            return vec![Self { start: 0, end: modulus }];
+        }
+
+        let start = (self.start as i128 + delta as i128).rem_euclid(modulus as i128) as u64;
+        // Compare against the space left after `start`, as `start + len` may not fit in a `u64`.
+        let room = modulus - start;
+
+        if self.len() <= room {
+            vec![Self { start, end: start + self.len() }]
+        } else {
+            vec![
+                Self { start, end: modulus },
+                Self { start: 0, end: self.len() - room },
+            ]
+        }
     }
 }
 
@@ -87,7 +345,7 @@ impl PartialEq for Range1D {
 /// Below you can find a set of unit tests.
 #[cfg(test)]
 mod tests {
-    use crate::Range1D;
+    use crate::{Range1D, RangeError, RangeTooLarge};
 
     #[test]
     #[should_panic(expected = "Start must not be larger than end")]
@@ -96,6 +354,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn create_single_item_range() {
         let range = Range1D::new(1, 1).unwrap();
         assert_eq!(range.start(), 1);
@@ -104,6 +363,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn create_range() {
         let range = Range1D::new(1, 5).unwrap();
         assert_eq!(range.start(), 1);
@@ -112,14 +372,16 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn correct_method_types() {
         let range = Range1D::new(1, 5).unwrap();
         assert_eq!(range.start(), 1u64);
         assert_eq!(range.end(), 5u64);
-        assert_eq!(range.len(), 5usize);
+        assert_eq!(range.len(), 5u64);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn create_range_large() {
         let range = Range1D::new(1, 50000000000000000).unwrap();
         assert_eq!(range.start(), 1);
@@ -165,6 +427,35 @@ mod tests {
         assert!(range.contains(18));
     }
 
+    #[test]
+    fn contains_inclusive_end() {
+        let range = Range1D::new(1, 5).unwrap();
+        assert!(range.contains(1));
+        assert!(range.contains(5));
+        assert!(!range.contains(6));
+
+        let single = Range1D::new(7, 7).unwrap();
+        assert!(single.contains(7));
+    }
+
+    #[test]
+    fn nth_element() {
+        let range = Range1D::new(20, 25).unwrap();
+        assert_eq!(range.nth(0), Some(20));
+        assert_eq!(range.nth(5), Some(25));
+        assert_eq!(range.nth(6), None);
+        assert_eq!(Range1D::new(0, u64::MAX - 1).unwrap().nth(u64::MAX - 1), Some(u64::MAX - 1));
+    }
+
+    #[test]
+    fn nth_from_end() {
+        let range = Range1D::new(20, 25).unwrap();
+        assert_eq!(range.nth_from_end(0), Some(25));
+        assert_eq!(range.nth_from_end(2), Some(23));
+        assert_eq!(range.nth_from_end(5), Some(20));
+        assert_eq!(range.nth_from_end(6), None);
+    }
+
     #[test]
     fn iterate_single() {
         let range = Range1D::new(14, 14).unwrap();
@@ -249,4 +540,320 @@ mod tests {
         let b = Range1D::new(23, 28).unwrap();
         assert_eq!(a.intersect(b), Some(Range1D::new(23, 25).unwrap()));
     }
+
+    #[test]
+    fn intersect_required() {
+        let a = Range1D::new(1, 10).unwrap();
+        assert_eq!(
+            a.intersect_required(Range1D::new(5, 20).unwrap()),
+            Ok(Range1D::new(5, 10).unwrap())
+        );
+        assert_eq!(
+            a.intersect_required(Range1D::new(10, 10).unwrap()),
+            Ok(Range1D::new(10, 10).unwrap())
+        );
+        assert_eq!(
+            a.intersect_required(Range1D::new(11, 20).unwrap()),
+            Err(RangeError::Disjoint)
+        );
+    }
+
+    #[test]
+    fn map_double() {
+        let range = Range1D::new(3, 7).unwrap();
+        assert_eq!(range.map(|x| x * 2), Ok(Range1D::new(6, 14).unwrap()));
+    }
+
+    #[test]
+    fn map_not_monotonic() {
+        let range = Range1D::new(3, 7).unwrap();
+        assert_eq!(
+            range.map(|x| 100 - x),
+            Err(RangeError::NotMonotonic { start: 97, end: 93 })
+        );
+    }
+
+    #[test]
+    fn map_to_max() {
+        let range = Range1D::new(3, 7).unwrap();
+        assert_eq!(range.map(|_| u64::MAX), Err(RangeError::EndTooLarge));
+        let shifted = Range1D::new(u64::MAX - 5, u64::MAX - 1).unwrap();
+        assert_eq!(range.map(|x| x + (u64::MAX - 8)), Ok(shifted));
+    }
+
+    #[test]
+    fn inclusive_accessors() {
+        let range = Range1D::new(1, 5).unwrap();
+        assert_eq!(range.start_inclusive(), 1);
+        assert_eq!(range.end_inclusive(), 5);
+        assert_eq!(range.start_inclusive(), range.start());
+    }
+
+    #[test]
+    fn first_and_last() {
+        let range = Range1D::new(3, 9).unwrap();
+        assert_eq!(range.first(), 3);
+        assert_eq!(range.last(), 9);
+        assert_eq!(range.first(), range.start());
+        assert_eq!(range.last(), range.end_inclusive());
+        assert_eq!(range.iter().next(), Some(range.first()));
+        assert_eq!(range.iter().last(), Some(range.last()));
+    }
+
+    #[test]
+    fn sum_matches_iteration() {
+        let range = Range1D::new(3, 17).unwrap();
+        assert_eq!(range.sum(), range.iter().map(u128::from).sum::<u128>());
+        assert_eq!(Range1D::new(5, 5).unwrap().sum(), 5);
+    }
+
+    #[test]
+    fn sum_large() {
+        let range = Range1D::new(1, u64::MAX - 1).unwrap();
+        let n = (u64::MAX - 1) as u128;
+        assert_eq!(range.sum(), n * (n + 1) / 2);
+    }
+
+    #[test]
+    fn clamp_point() {
+        let range = Range1D::new(10, 20).unwrap();
+        assert_eq!(range.clamp(5), 10);
+        assert_eq!(range.clamp(10), 10);
+        assert_eq!(range.clamp(15), 15);
+        assert_eq!(range.clamp(20), 20);
+        assert_eq!(range.clamp(50), 20);
+    }
+
+    #[test]
+    fn grow_to_include() {
+        let range = Range1D::new(5, 10).unwrap();
+        assert_eq!(range.grow_to_include(2), Ok(Range1D::new(2, 10).unwrap()));
+        assert_eq!(range.grow_to_include(12), Ok(Range1D::new(5, 12).unwrap()));
+        assert_eq!(range.grow_to_include(7), Ok(range));
+        assert_eq!(range.grow_to_include(10), Ok(range));
+        assert_eq!(range.grow_to_include(5), Ok(range));
+        assert_eq!(range.grow_to_include(u64::MAX), Err(RangeError::EndTooLarge));
+    }
+
+    #[test]
+    fn clamp_iterator() {
+        let range = Range1D::new(10, 20).unwrap();
+        let clamped: Vec<u64> = range.clamp_iter(vec![5, 50, 15].into_iter()).collect();
+        assert_eq!(clamped, vec![10, 20, 15]);
+    }
+
+    #[test]
+    fn overlap_len_overlapping() {
+        let a = Range1D::new(20, 25).unwrap();
+        let b = Range1D::new(23, 28).unwrap();
+        assert_eq!(a.overlap_len(&b), 3);
+        assert_eq!(b.overlap_len(&a), 3);
+        assert_eq!(a.overlap_len(&Range1D::new(25, 30).unwrap()), 1);
+    }
+
+    #[test]
+    fn overlap_len_nested() {
+        let a = Range1D::new(10, 80).unwrap();
+        let b = Range1D::new(24, 38).unwrap();
+        assert_eq!(a.overlap_len(&b), b.len());
+        assert_eq!(a.overlap_len(&a), a.len());
+    }
+
+    #[test]
+    fn overlap_len_disjoint() {
+        let a = Range1D::new(20, 25).unwrap();
+        assert_eq!(a.overlap_len(&Range1D::new(18, 19).unwrap()), 0);
+        assert_eq!(a.overlap_len(&Range1D::new(26, 30).unwrap()), 0);
+    }
+
+    #[test]
+    fn densify_small() {
+        let range = Range1D::new(3, 7).unwrap();
+        assert_eq!(range.densify(5).unwrap(), vec![3, 4, 5, 6, 7].into_boxed_slice());
+        assert_eq!(Range1D::new(9, 9).unwrap().densify(1).unwrap()[..], [9]);
+    }
+
+    #[test]
+    fn densify_too_large() {
+        let range = Range1D::new(0, u64::MAX - 1).unwrap();
+        assert_eq!(range.densify(1000), Err(RangeTooLarge { len: u64::MAX, max: 1000 }));
+        assert_eq!(
+            Range1D::new(3, 7).unwrap().densify(4),
+            Err(RangeTooLarge { len: 5, max: 4 })
+        );
+    }
+
+    #[test]
+    fn wrapping_shift_without_wrap() {
+        let range = Range1D::new(8, 10).unwrap();
+        assert_eq!(range.wrapping_shift(5, 12), vec![Range1D::new(1, 3).unwrap()]);
+        assert_eq!(range.wrapping_shift(-8, 12), vec![Range1D::new(0, 2).unwrap()]);
+        assert_eq!(range.wrapping_shift(0, 12), vec![range]);
+    }
+
+    #[test]
+    fn wrapping_shift_splits() {
+        let range = Range1D::new(8, 10).unwrap();
+        assert_eq!(
+            range.wrapping_shift(2, 12),
+            vec![Range1D::new(10, 11).unwrap(), Range1D::new(0, 0).unwrap()]
+        );
+        assert_eq!(
+            range.wrapping_shift(-9, 12),
+            vec![Range1D::new(11, 11).unwrap(), Range1D::new(0, 1).unwrap()]
+        );
+    }
+
+    #[test]
+    fn wrapping_shift_whole_space() {
+        let range = Range1D::new(3, 20).unwrap();
+        assert_eq!(range.wrapping_shift(7, 10), vec![Range1D::new(0, 9).unwrap()]);
+    }
+
+    #[test]
+    fn wrapping_shift_near_max_modulus() {
+        let range = Range1D::new(0, u64::MAX - 2).unwrap();
+        assert_eq!(
+            range.wrapping_shift(10, u64::MAX),
+            vec![Range1D::new(10, u64::MAX - 1).unwrap(), Range1D::new(0, 8).unwrap()]
+        );
+        assert_eq!(
+            range.wrapping_shift(-1, u64::MAX),
+            vec![
+                Range1D::new(u64::MAX - 1, u64::MAX - 1).unwrap(),
+                Range1D::new(0, u64::MAX - 3).unwrap(),
+            ]
+        );
+        assert_eq!(range.wrapping_shift(0, u64::MAX), vec![range]);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must not be zero")]
+    fn wrapping_shift_zero_modulus() {
+        Range1D::new(1, 2).unwrap().wrapping_shift(1, 0);
+    }
+
+    #[test]
+    fn unit_range() {
+        let range = Range1D::unit(7).unwrap();
+        assert_eq!(range.start_inclusive(), 7);
+        assert_eq!(range.end_inclusive(), 7);
+        assert_eq!(range.len(), 1);
+        assert_eq!(range, Range1D::new(7, 7).unwrap());
+        assert_eq!(Range1D::unit(u64::MAX - 1).unwrap().len(), 1);
+        assert_eq!(Range1D::unit(u64::MAX), Err(RangeError::EndTooLarge));
+    }
+
+    #[test]
+    fn to_bitset() {
+        let universe = Range1D::new(0, 9).unwrap();
+        let range = Range1D::new(3, 5).unwrap();
+        assert_eq!(
+            range.to_bitset(universe),
+            Ok(vec![false, false, false, true, true, true, false, false, false, false])
+        );
+        assert_eq!(universe.to_bitset(universe), Ok(vec![true; 10]));
+    }
+
+    #[test]
+    fn to_bitset_outside_universe() {
+        let universe = Range1D::new(10, 19).unwrap();
+        assert_eq!(
+            Range1D::new(5, 12).unwrap().to_bitset(universe),
+            Err(RangeError::OutsideUniverse { start: 5, end: 12 })
+        );
+        assert_eq!(
+            Range1D::new(15, 20).unwrap().to_bitset(universe),
+            Err(RangeError::OutsideUniverse { start: 15, end: 20 })
+        );
+    }
+
+    #[test]
+    fn split_into_chunks() {
+        let range = Range1D::new(10, 19).unwrap();
+        assert_eq!(
+            range.split_into(3),
+            vec![
+                Range1D::new(10, 13).unwrap(),
+                Range1D::new(14, 16).unwrap(),
+                Range1D::new(17, 19).unwrap(),
+            ]
+        );
+        assert_eq!(range.split_into(1), vec![range]);
+        assert_eq!(range.split_into(100).len(), 10);
+        assert!(range.split_into(0).is_empty());
+    }
+
+    #[test]
+    fn subdivide_by_size_with_remainder() {
+        let range = Range1D::new(0, 9).unwrap();
+        assert_eq!(
+            range.subdivide_by_size(4),
+            vec![
+                Range1D::new(0, 3).unwrap(),
+                Range1D::new(4, 7).unwrap(),
+                Range1D::new(8, 9).unwrap(),
+            ]
+        );
+        assert_eq!(range.subdivide_by_size(20), vec![range]);
+        assert!(range.subdivide_by_size(0).is_empty());
+    }
+
+    #[test]
+    fn subdivide_by_size_even() {
+        let range = Range1D::new(10, 15).unwrap();
+        assert_eq!(
+            range.subdivide_by_size(2),
+            vec![
+                Range1D::new(10, 11).unwrap(),
+                Range1D::new(12, 13).unwrap(),
+                Range1D::new(14, 15).unwrap(),
+            ]
+        );
+        assert_eq!(range.subdivide_by_size(1).len(), 6);
+        assert_eq!(range.subdivide_by_size(6), vec![range]);
+    }
+
+    #[test]
+    fn partition_by_even() {
+        let range = Range1D::new(0, 9).unwrap();
+        let expected: Vec<Range1D> = (0..10).step_by(2).map(|x| Range1D::unit(x).unwrap()).collect();
+        assert_eq!(range.partition_by(|x| x % 2 == 0), expected);
+    }
+
+    #[test]
+    fn partition_by_runs() {
+        let range = Range1D::new(0, 20).unwrap();
+        assert_eq!(
+            range.partition_by(|x| x % 10 < 3),
+            vec![
+                Range1D::new(0, 2).unwrap(),
+                Range1D::new(10, 12).unwrap(),
+                Range1D::new(20, 20).unwrap(),
+            ]
+        );
+        assert_eq!(range.partition_by(|_| true), vec![range]);
+        assert!(range.partition_by(|_| false).is_empty());
+    }
+
+    #[test]
+    fn chunked_sum_matches_closed_form() {
+        let range = Range1D::new(1_000, 1_000_000).unwrap();
+        let sum: u128 = range.split_into(7).iter().map(|chunk| chunk.sum()).sum();
+        assert_eq!(sum, range.sum());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_sum_matches_closed_form() {
+        use rayon::prelude::*;
+
+        let range = Range1D::new(1_000, 1_000_000).unwrap();
+        let sum: u128 = range.par_iter().map(u128::from).sum();
+        assert_eq!(sum, range.sum());
+
+        let sum: u128 = range.par_chunks(7).map(|chunk| chunk.sum()).sum();
+        assert_eq!(sum, range.sum());
+        assert_eq!(range.par_chunks(7).len(), 7);
+    }
 }
\ No newline at end of file
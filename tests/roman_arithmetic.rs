@@ -0,0 +1,172 @@
+//! Run this file with `cargo test --test roman_arithmetic`.
+
+//! TODO: Implement arithmetic on [Roman numerals](https://en.wikipedia.org/wiki/Roman_numerals)
+//! given as strings.
+//!
+//! - `to_roman`: converts a number between 1 and 3999 into its Roman numeral, e.g. 1994 into
+//!   `MCMXCIV`.
+//! - `from_roman`: converts a Roman numeral back into a number. Only accept numerals in their
+//!   standard form, i.e. exactly the strings `to_roman` produces (`IIII` or `IC` are invalid).
+//! - `roman_add`, `roman_subtract` and `roman_multiply`: convert both operands into numbers,
+//!   compute the result and convert it back.
+//!
+//! Roman numerals cannot represent zero, negative numbers or numbers above 3999, so results
+//! outside of `1..=3999` are an error.
+//!
+//! Hint: an easy way to validate a numeral is to convert it into a number and back, and check
+//! that the result equals the input.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq)]
+enum RomanError {
+    InvalidNumeral(String),
+    OutOfRange(i64),
+}
+
+impl Display for RomanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for RomanError {}
+
+/// The values of the numerals, including the subtractive pairs, from the largest to the smallest.
+const NUMERALS: [(i64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+fn to_roman(mut value: i64) -> Result<String, RomanError> {
+    if !(1..=3999).contains(&value) {
+        return Err(RomanError::OutOfRange(value));
+    }
+
+    let mut roman = String::new();
+    for (numeral_value, numeral) in NUMERALS {
+        while value >= numeral_value {
+            roman.push_str(numeral);
+            value -= numeral_value;
+        }
+    }
+    Ok(roman)
+}
+
+fn from_roman(roman: &str) -> Result<i64, RomanError> {
+    let mut value = 0;
+    let mut rest = roman;
+    for (numeral_value, numeral) in NUMERALS {
+        while let Some(remaining) = rest.strip_prefix(numeral) {
+            value += numeral_value;
+            rest = remaining;
+        }
+    }
+
+    // Leftover characters are invalid, and non-standard forms like `IIII` convert back differently.
+    if !rest.is_empty() || to_roman(value).ok().as_deref() != Some(roman) {
+        return Err(RomanError::InvalidNumeral(roman.to_string()));
+    }
+    Ok(value)
+}
+
+fn roman_add(a: &str, b: &str) -> Result<String, RomanError> {
+    to_roman(from_roman(a)? + from_roman(b)?)
+}
+
+fn roman_subtract(a: &str, b: &str) -> Result<String, RomanError> {
+    to_roman(from_roman(a)? - from_roman(b)?)
+}
+
+fn roman_multiply(a: &str, b: &str) -> Result<String, RomanError> {
+    to_roman(from_roman(a)? * from_roman(b)?)
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{from_roman, roman_add, roman_multiply, roman_subtract, to_roman, RomanError};
+
+    #[test]
+    fn convert_to_roman() {
+        assert_eq!(to_roman(1), Ok("I".to_string()));
+        assert_eq!(to_roman(4), Ok("IV".to_string()));
+        assert_eq!(to_roman(14), Ok("XIV".to_string()));
+        assert_eq!(to_roman(1994), Ok("MCMXCIV".to_string()));
+        assert_eq!(to_roman(3999), Ok("MMMCMXCIX".to_string()));
+        assert_eq!(to_roman(0), Err(RomanError::OutOfRange(0)));
+        assert_eq!(to_roman(4000), Err(RomanError::OutOfRange(4000)));
+    }
+
+    #[test]
+    fn convert_from_roman() {
+        assert_eq!(from_roman("III"), Ok(3));
+        assert_eq!(from_roman("XLII"), Ok(42));
+        assert_eq!(from_roman("MCMXCIV"), Ok(1994));
+    }
+
+    #[test]
+    fn invalid_numerals() {
+        for numeral in ["", "IIII", "IC", "VV", "MMMM", "XM", "iv", "X1", "IVI"] {
+            assert_eq!(
+                from_roman(numeral),
+                Err(RomanError::InvalidNumeral(numeral.to_string())),
+                "{numeral}"
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in 1..=3999 {
+            assert_eq!(from_roman(&to_roman(value).unwrap()), Ok(value));
+        }
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(roman_add("II", "III"), Ok("V".to_string()));
+        assert_eq!(roman_add("XIX", "I"), Ok("XX".to_string()));
+        assert_eq!(roman_add("MM", "MCMXCIX"), Ok("MMMCMXCIX".to_string()));
+        assert_eq!(roman_add("MM", "MM"), Err(RomanError::OutOfRange(4000)));
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(roman_subtract("X", "I"), Ok("IX".to_string()));
+        assert_eq!(roman_subtract("MCMXCIV", "CMXCIV"), Ok("M".to_string()));
+        assert_eq!(roman_subtract("V", "V"), Err(RomanError::OutOfRange(0)));
+        assert_eq!(roman_subtract("I", "X"), Err(RomanError::OutOfRange(-9)));
+    }
+
+    #[test]
+    fn multiply() {
+        assert_eq!(roman_multiply("VI", "VII"), Ok("XLII".to_string()));
+        assert_eq!(roman_multiply("I", "MMM"), Ok("MMM".to_string()));
+        assert_eq!(roman_multiply("C", "XL"), Err(RomanError::OutOfRange(4000)));
+    }
+
+    #[test]
+    fn invalid_operands() {
+        assert_eq!(
+            roman_add("IIII", "I"),
+            Err(RomanError::InvalidNumeral("IIII".to_string()))
+        );
+        assert_eq!(
+            roman_multiply("X", "Z"),
+            Err(RomanError::InvalidNumeral("Z".to_string()))
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Run this file with `cargo test --test roman_clock`.
+
+//! TODO: Implement a function called `roman_time`, which formats a time of day with
+//! [Roman numerals](https://en.wikipedia.org/wiki/Roman_numerals), separating the hours, minutes
+//! and seconds with colons, e.g. `XIV:XXIX:LV` for 14:29:55.
+//!
+//! The hours must be between 0 and 23, the minutes and seconds between 0 and 59, otherwise return
+//! an error. The Romans had no numeral for zero, so write `N` (for "nulla") instead, e.g.
+//! `N:N:N` for midnight.
+//!
+//! Hint: numbers below 60 only need the numerals `L`, `X`, `V` and `I` together with the
+//! subtractive forms `XL`, `IX` and `IV`.
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// The component of the time that is out of range, with its value.
+#[derive(Debug, Eq, PartialEq)]
+enum TimeError {
+    Hours(u8),
+    Minutes(u8),
+    Seconds(u8),
+}
+
+impl Display for TimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for TimeError {}
+
+/// The numerals needed for numbers below 60, from the largest to the smallest.
+const NUMERALS: [(u8, &str); 7] = [
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Converts a number below 60 into a Roman numeral, writing zero as `N`.
+fn to_roman(mut value: u8) -> String {
+    if value == 0 {
+        return "N".to_string();
+    }
+
+    let mut roman = String::new();
+    for (numeral_value, numeral) in NUMERALS {
+        while value >= numeral_value {
+            roman.push_str(numeral);
+            value -= numeral_value;
+        }
+    }
+    roman
+}
+
+fn roman_time(hours: u8, minutes: u8, seconds: u8) -> Result<String, TimeError> {
+    if hours > 23 {
+        return Err(TimeError::Hours(hours));
+    }
+    if minutes > 59 {
+        return Err(TimeError::Minutes(minutes));
+    }
+    if seconds > 59 {
+        return Err(TimeError::Seconds(seconds));
+    }
+
+    Ok(format!(
+        "{}:{}:{}",
+        to_roman(hours),
+        to_roman(minutes),
+        to_roman(seconds)
+    ))
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{roman_time, to_roman, TimeError};
+
+    #[test]
+    fn midnight() {
+        assert_eq!(roman_time(0, 0, 0), Ok("N:N:N".to_string()));
+    }
+
+    #[test]
+    fn noon() {
+        assert_eq!(roman_time(12, 0, 0), Ok("XII:N:N".to_string()));
+    }
+
+    #[test]
+    fn known_times() {
+        assert_eq!(roman_time(14, 29, 55), Ok("XIV:XXIX:LV".to_string()));
+        assert_eq!(roman_time(23, 59, 59), Ok("XXIII:LIX:LIX".to_string()));
+        assert_eq!(roman_time(9, 4, 44), Ok("IX:IV:XLIV".to_string()));
+        assert_eq!(roman_time(0, 1, 0), Ok("N:I:N".to_string()));
+    }
+
+    #[test]
+    fn all_numerals() {
+        assert_eq!(to_roman(8), "VIII");
+        assert_eq!(to_roman(19), "XIX");
+        assert_eq!(to_roman(38), "XXXVIII");
+        assert_eq!(to_roman(49), "XLIX");
+        // Every number below 60 gets a distinct numeral.
+        let numerals: std::collections::HashSet<String> = (0..60).map(to_roman).collect();
+        assert_eq!(numerals.len(), 60);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(roman_time(24, 0, 0), Err(TimeError::Hours(24)));
+        assert_eq!(roman_time(12, 60, 0), Err(TimeError::Minutes(60)));
+        assert_eq!(roman_time(12, 30, 60), Err(TimeError::Seconds(60)));
+        assert_eq!(roman_time(255, 255, 255), Err(TimeError::Hours(255)));
+    }
+}
@@ -0,0 +1,156 @@
+//! Run this file with `cargo test --test run_length_encoding_unicode`.
+
+//! TODO: Implement [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding) for
+//! arbitrary Unicode text.
+//!
+//! - `encode`: replaces every run of the same character by the length of the run followed by the
+//!   character. Runs of a single character are written without a length, so `"aaabcc"` becomes
+//!   `"3ab2c"` and `"🎉🎉🎉"` becomes `"3🎉"`.
+//! - `decode`: the inverse of `encode`, so `"3🎉"` becomes `"🎉🎉🎉"`. Fail if a length is not
+//!   followed by a character, is zero or does not fit into a `usize`.
+//!
+//! The lengths are written with ASCII digits, so you may assume that the text to encode contains
+//! none.
+//!
+//! Hint: many characters, like the emoji above, take up several bytes in UTF-8. Iterate over the
+//! `char`s of the string, not over its bytes, otherwise you would count (and split) the individual
+//! bytes of a character.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq)]
+enum DecodeError {
+    /// The text ends with a length that is not followed by a character.
+    MissingCharacter,
+    /// A run has the length zero, which `encode` never produces.
+    ZeroCount,
+    CountTooLarge,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for DecodeError {}
+
+fn encode(text: &str) -> String {
+    let mut encoded = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let mut count = 1;
+        while chars.next_if_eq(&c).is_some() {
+            count += 1;
+        }
+        if count > 1 {
+            encoded.push_str(&count.to_string());
+        }
+        encoded.push(c);
+    }
+    encoded
+}
+
+fn decode(encoded: &str) -> Result<String, DecodeError> {
+    let mut decoded = String::new();
+    // The length read so far, `None` if the next character is not preceded by a length.
+    let mut count: Option<usize> = None;
+
+    for c in encoded.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            count = Some(
+                count
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|count| count.checked_add(digit as usize))
+                    .ok_or(DecodeError::CountTooLarge)?,
+            );
+            continue;
+        }
+
+        match count.take() {
+            Some(0) => return Err(DecodeError::ZeroCount),
+            Some(count) => decoded.extend(std::iter::repeat_n(c, count)),
+            None => decoded.push(c),
+        }
+    }
+
+    if count.is_some() {
+        return Err(DecodeError::MissingCharacter);
+    }
+    Ok(decoded)
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode, DecodeError};
+
+    #[test]
+    fn empty() {
+        assert_eq!(encode(""), "");
+        assert_eq!(decode(""), Ok(String::new()));
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(encode("aaabcc"), "3ab2c");
+        assert_eq!(encode("WWWWWWWWWWWWBWWWWWWWWWWWWBBB"), "12WB12W3B");
+        assert_eq!(decode("3ab2c"), Ok("aaabcc".to_string()));
+        assert_eq!(decode("12WB12W3B"), Ok("WWWWWWWWWWWWBWWWWWWWWWWWWBBB".to_string()));
+    }
+
+    #[test]
+    fn emoji() {
+        assert_eq!(encode("🎉🎉🎉"), "3🎉");
+        assert_eq!(decode("3🎉"), Ok("🎉🎉🎉".to_string()));
+        // A four-byte character must not be counted as four characters.
+        assert_eq!(encode("🎉"), "🎉");
+        assert_eq!(decode("🎉"), Ok("🎉".to_string()));
+    }
+
+    #[test]
+    fn mixed_ascii_and_unicode() {
+        assert_eq!(encode("aaéééb🎉🎉  ü"), "2a3éb2🎉2 ü");
+        assert_eq!(decode("2a3éb2🎉2 ü"), Ok("aaéééb🎉🎉  ü".to_string()));
+        assert_eq!(encode("ßßẞ"), "2ßẞ");
+        assert_eq!(encode("日日日本本"), "3日2本");
+    }
+
+    #[test]
+    fn high_code_points() {
+        // U+1F600 and its neighbour U+1F601 share their first three UTF-8 bytes.
+        assert_eq!(encode("😀😀😁😁😁"), "2😀3😁");
+        assert_eq!(decode("2😀3😁"), Ok("😀😀😁😁😁".to_string()));
+        assert_eq!(encode("\u{10FFFF}\u{10FFFF}"), "2\u{10FFFF}");
+
+        let long_run = "🦀".repeat(1000);
+        assert_eq!(encode(&long_run), "1000🦀");
+        assert_eq!(decode("1000🦀"), Ok(long_run));
+    }
+
+    #[test]
+    fn emoji_sequences_are_encoded_per_char() {
+        // A family emoji consists of several characters joined by zero width joiners.
+        let family = "👨\u{200D}👩\u{200D}👧";
+        assert_eq!(encode(family), family);
+        assert_eq!(encode("👍🏽👍🏽"), "👍🏽👍🏽");
+        assert_eq!(decode(&encode(family)), Ok(family.to_string()));
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        assert_eq!(decode("3a2"), Err(DecodeError::MissingCharacter));
+        assert_eq!(decode("0🎉"), Err(DecodeError::ZeroCount));
+        assert_eq!(decode("99999999999999999999999a"), Err(DecodeError::CountTooLarge));
+    }
+
+    #[test]
+    fn round_trip() {
+        for text in ["abc", "aabbbcccc", "ñññ🎉🎉a", "  \t\t\n", "ÿÿÿÿÿÿÿÿÿÿÿ"] {
+            assert_eq!(decode(&encode(text)), Ok(text.to_string()), "{text}");
+        }
+    }
+}
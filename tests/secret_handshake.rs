@@ -0,0 +1,98 @@
+//! Run this file with `cargo test --test secret_handshake`.
+
+//! TODO: Implement a function called `actions`, which decodes a secret handshake from a number.
+//! The lowest five bits of the number describe the handshake:
+//! - `1` (bit 0): wink
+//! - `2` (bit 1): double blink
+//! - `4` (bit 2): close your eyes
+//! - `8` (bit 3): jump
+//! - `16` (bit 4): reverse the order of the actions
+//!
+//! Actions are returned in the order of their bits, starting from the lowest one, unless bit 4 is
+//! set. Higher bits are ignored.
+
+const ACTIONS: [&str; 4] = ["wink", "double blink", "close your eyes", "jump"];
+const REVERSE: u32 = 1 << 4;
+
+fn actions(code: u32) -> Vec<&'static str> {
+    let mut result: Vec<&'static str> = ACTIONS
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| code & (1 << bit) != 0)
+        .map(|(_, action)| *action)
+        .collect();
+
+    if code & REVERSE != 0 {
+        result.reverse();
+    }
+
+    result
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::actions;
+
+    #[test]
+    fn handshake_empty() {
+        assert!(actions(0).is_empty());
+        assert!(actions(16).is_empty());
+    }
+
+    #[test]
+    fn handshake_single_actions() {
+        assert_eq!(actions(1), vec!["wink"]);
+        assert_eq!(actions(2), vec!["double blink"]);
+        assert_eq!(actions(4), vec!["close your eyes"]);
+        assert_eq!(actions(8), vec!["jump"]);
+    }
+
+    #[test]
+    fn handshake_combined_actions() {
+        assert_eq!(actions(3), vec!["wink", "double blink"]);
+        assert_eq!(actions(9), vec!["wink", "jump"]);
+        assert_eq!(actions(19), vec!["double blink", "wink"]);
+    }
+
+    #[test]
+    fn handshake_all_actions() {
+        assert_eq!(actions(15), vec!["wink", "double blink", "close your eyes", "jump"]);
+        assert_eq!(actions(31), vec!["jump", "close your eyes", "double blink", "wink"]);
+    }
+
+    #[test]
+    fn handshake_every_code() {
+        let names = ["wink", "double blink", "close your eyes", "jump"];
+
+        for code in 0..32u32 {
+            let mut expected = vec![];
+            for (bit, name) in names.iter().enumerate() {
+                if code & (1 << bit) != 0 {
+                    expected.push(*name);
+                }
+            }
+            if code >= 16 {
+                expected.reverse();
+            }
+
+            assert_eq!(actions(code), expected, "code {code}");
+        }
+    }
+
+    #[test]
+    fn handshake_reverse_flag() {
+        for code in 0..16u32 {
+            let mut reversed = actions(code);
+            reversed.reverse();
+            assert_eq!(actions(code | 16), reversed, "code {code}");
+        }
+    }
+
+    #[test]
+    fn handshake_ignores_higher_bits() {
+        assert_eq!(actions(32), actions(0));
+        assert_eq!(actions(33), actions(1));
+        assert_eq!(actions(0xffff_fff3), actions(19));
+    }
+}
@@ -0,0 +1,179 @@
+//! Run this file with `cargo test --test segment_tree`.
+
+//! TODO: Implement a [segment tree](https://en.wikipedia.org/wiki/Segment_tree) over a list of
+//! `i64` values, which answers range queries and supports point updates.
+//!
+//! - `new`: builds the tree from a slice of values in O(n).
+//! - `query_sum`: returns the sum of the values at indices `start..=end` (both inclusive).
+//! - `query_min`: returns the minimum of the values at indices `start..=end` (both inclusive).
+//! - `update`: replaces the value at `index`.
+//!
+//! Queries and updates should run in O(log n). Queries with `start > end` or indices outside of
+//! the values should panic.
+//!
+//! Hint: a complete binary tree can be stored in a `Vec` without any pointers. With `n` values,
+//! store the leaves at indices `n..2n` and the parent of node `i` at index `i / 2`.
+
+struct SegmentTree {
+    /// Sums of the nodes, the leaves are stored at `len..2 * len`.
+    data: Vec<i64>,
+    /// Minimums of the nodes, laid out like `data`.
+    mins: Vec<i64>,
+    len: usize,
+}
+
+impl SegmentTree {
+    fn new(values: &[i64]) -> Self {
+        let len = values.len();
+        let mut data = vec![0; 2 * len];
+        let mut mins = vec![i64::MAX; 2 * len];
+        data[len..].copy_from_slice(values);
+        mins[len..].copy_from_slice(values);
+
+        for node in (1..len).rev() {
+            data[node] = data[2 * node] + data[2 * node + 1];
+            mins[node] = mins[2 * node].min(mins[2 * node + 1]);
+        }
+
+        Self { data, mins, len }
+    }
+
+    /// Combines the nodes covering `start..=end` using `combine`, starting from `identity`.
+    fn query(
+        &self,
+        tree: &[i64],
+        start: usize,
+        end: usize,
+        identity: i64,
+        combine: fn(i64, i64) -> i64,
+    ) -> i64 {
+        assert!(start <= end && end < self.len, "invalid range {start}..={end}");
+
+        let mut result = identity;
+        let mut left = start + self.len;
+        let mut right = end + self.len + 1;
+
+        while left < right {
+            if left % 2 == 1 {
+                result = combine(result, tree[left]);
+                left += 1;
+            }
+            if right % 2 == 1 {
+                right -= 1;
+                result = combine(result, tree[right]);
+            }
+            left /= 2;
+            right /= 2;
+        }
+
+        result
+    }
+
+    fn query_sum(&self, start: usize, end: usize) -> i64 {
+        self.query(&self.data, start, end, 0, |a, b| a + b)
+    }
+
+    fn query_min(&self, start: usize, end: usize) -> i64 {
+        self.query(&self.mins, start, end, i64::MAX, i64::min)
+    }
+
+    fn update(&mut self, index: usize, new_value: i64) {
+        assert!(index < self.len, "index {index} out of bounds");
+
+        let mut node = index + self.len;
+        self.data[node] = new_value;
+        self.mins[node] = new_value;
+
+        while node > 1 {
+            node /= 2;
+            self.data[node] = self.data[2 * node] + self.data[2 * node + 1];
+            self.mins[node] = self.mins[2 * node].min(self.mins[2 * node + 1]);
+        }
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::SegmentTree;
+
+    const VALUES: [i64; 8] = [5, -3, 8, 1, 0, 7, -6, 4];
+
+    #[test]
+    fn sum_whole_range() {
+        let tree = SegmentTree::new(&VALUES);
+        assert_eq!(tree.query_sum(0, 7), 16);
+    }
+
+    #[test]
+    fn sum_ranges() {
+        let tree = SegmentTree::new(&VALUES);
+        assert_eq!(tree.query_sum(0, 2), 10);
+        assert_eq!(tree.query_sum(2, 5), 16);
+        assert_eq!(tree.query_sum(5, 7), 5);
+        assert_eq!(tree.query_sum(1, 6), 7);
+    }
+
+    #[test]
+    fn min_ranges() {
+        let tree = SegmentTree::new(&VALUES);
+        assert_eq!(tree.query_min(0, 7), -6);
+        assert_eq!(tree.query_min(0, 2), -3);
+        assert_eq!(tree.query_min(2, 5), 0);
+        assert_eq!(tree.query_min(2, 3), 1);
+    }
+
+    #[test]
+    fn single_element_queries() {
+        let tree = SegmentTree::new(&VALUES);
+        for (idx, value) in VALUES.iter().enumerate() {
+            assert_eq!(tree.query_sum(idx, idx), *value);
+            assert_eq!(tree.query_min(idx, idx), *value);
+        }
+    }
+
+    #[test]
+    fn single_value_tree() {
+        let mut tree = SegmentTree::new(&[42]);
+        assert_eq!(tree.query_sum(0, 0), 42);
+        assert_eq!(tree.query_min(0, 0), 42);
+        tree.update(0, -1);
+        assert_eq!(tree.query_sum(0, 0), -1);
+    }
+
+    #[test]
+    fn update_and_requery() {
+        let mut tree = SegmentTree::new(&VALUES);
+        tree.update(6, 10);
+        assert_eq!(tree.query_sum(0, 7), 32);
+        assert_eq!(tree.query_min(0, 7), -3);
+        assert_eq!(tree.query_sum(5, 6), 17);
+
+        tree.update(0, -20);
+        assert_eq!(tree.query_min(0, 7), -20);
+        assert_eq!(tree.query_min(1, 7), -3);
+        assert_eq!(tree.query_sum(0, 1), -23);
+    }
+
+    #[test]
+    fn matches_naive_for_odd_length() {
+        let mut values = vec![3, -1, 4, 1, -5, 9, 2, -6, 5, 3, 5];
+        let mut tree = SegmentTree::new(&values);
+        tree.update(4, 7);
+        values[4] = 7;
+
+        for start in 0..values.len() {
+            for end in start..values.len() {
+                let slice = &values[start..=end];
+                assert_eq!(tree.query_sum(start, end), slice.iter().sum::<i64>());
+                assert_eq!(tree.query_min(start, end), *slice.iter().min().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid range")]
+    fn query_out_of_bounds() {
+        SegmentTree::new(&VALUES).query_sum(3, 8);
+    }
+}
@@ -0,0 +1,263 @@
+//! Run this file with `cargo test --test simple_calculator`.
+
+//! TODO: Implement a function called `evaluate`, which evaluates an arithmetic expression given as
+//! a string, e.g. `"2 * (3 + 4)"`.
+//!
+//! The expression can contain numbers (optionally with a decimal part), the binary operators
+//! `+`, `-`, `*` and `/`, unary minus (e.g. `-3` or `-(1 + 2)`) and parentheses. Whitespace is
+//! ignored. `*` and `/` bind tighter than `+` and `-`, and operators of the same precedence are
+//! evaluated from left to right.
+//!
+//! Use the [shunting-yard algorithm](https://en.wikipedia.org/wiki/Shunting_yard_algorithm) to
+//! convert the expression into postfix (reverse Polish) notation first, then evaluate the postfix
+//! expression using a stack.
+//!
+//! Report errors using the `CalcError` enum.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+enum CalcError {
+    MismatchedParentheses,
+    UnknownToken(char),
+    DivisionByZero,
+    /// The expression is missing an operand or an operator, e.g. `"1 +"` or `"1 2"`.
+    InvalidExpression,
+}
+
+impl Display for CalcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for CalcError {}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+}
+
+impl Operator {
+    fn precedence(self) -> u8 {
+        match self {
+            Operator::Add | Operator::Sub => 1,
+            Operator::Mul | Operator::Div => 2,
+            Operator::Neg => 3,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Op(Operator),
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>, CalcError> {
+    let mut tokens = vec![];
+    let mut chars = expr.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            let mut number = String::new();
+            while let Some(&d) = chars.peek() {
+                if !(d.is_ascii_digit() || d == '.') {
+                    break;
+                }
+                number.push(d);
+                chars.next();
+            }
+
+            let value = number.parse().map_err(|_| CalcError::InvalidExpression)?;
+            tokens.push(Token::Number(value));
+            continue;
+        }
+
+        // A minus is unary when it cannot refer to a left operand.
+        let follows_operand = matches!(tokens.last(), Some(Token::Number(_) | Token::RightParen));
+        let token = match c {
+            '+' => Token::Op(Operator::Add),
+            '-' if follows_operand => Token::Op(Operator::Sub),
+            '-' => Token::Op(Operator::Neg),
+            '*' => Token::Op(Operator::Mul),
+            '/' => Token::Op(Operator::Div),
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            _ => return Err(CalcError::UnknownToken(c)),
+        };
+        tokens.push(token);
+        chars.next();
+    }
+
+    Ok(tokens)
+}
+
+/// Converts infix tokens into postfix order.
+fn to_postfix(tokens: &[Token]) -> Result<Vec<Token>, CalcError> {
+    let mut output = vec![];
+    let mut stack: Vec<Token> = vec![];
+
+    for &token in tokens {
+        match token {
+            Token::Number(_) => output.push(token),
+            // Prefix operators have no left operand, so they never pop anything.
+            Token::Op(Operator::Neg) => stack.push(token),
+            Token::Op(op) => {
+                while let Some(&Token::Op(top)) = stack.last() {
+                    if top.precedence() < op.precedence() {
+                        break;
+                    }
+                    output.push(Token::Op(top));
+                    stack.pop();
+                }
+                stack.push(token);
+            }
+            Token::LeftParen => stack.push(token),
+            Token::RightParen => loop {
+                match stack.pop() {
+                    Some(Token::LeftParen) => break,
+                    Some(top) => output.push(top),
+                    None => return Err(CalcError::MismatchedParentheses),
+                }
+            },
+        }
+    }
+
+    while let Some(top) = stack.pop() {
+        if top == Token::LeftParen {
+            return Err(CalcError::MismatchedParentheses);
+        }
+        output.push(top);
+    }
+
+    Ok(output)
+}
+
+fn evaluate_postfix(postfix: &[Token]) -> Result<f64, CalcError> {
+    let mut stack = vec![];
+
+    for &token in postfix {
+        match token {
+            Token::Number(value) => stack.push(value),
+            Token::Op(Operator::Neg) => {
+                let value = stack.pop().ok_or(CalcError::InvalidExpression)?;
+                stack.push(-value);
+            }
+            Token::Op(op) => {
+                let right = stack.pop().ok_or(CalcError::InvalidExpression)?;
+                let left = stack.pop().ok_or(CalcError::InvalidExpression)?;
+                let result = match op {
+                    Operator::Add => left + right,
+                    Operator::Sub => left - right,
+                    Operator::Mul => left * right,
+                    Operator::Div if right == 0.0 => return Err(CalcError::DivisionByZero),
+                    Operator::Div => left / right,
+                    Operator::Neg => unreachable!(),
+                };
+                stack.push(result);
+            }
+            Token::LeftParen | Token::RightParen => unreachable!(),
+        }
+    }
+
+    match stack[..] {
+        [result] => Ok(result),
+        _ => Err(CalcError::InvalidExpression),
+    }
+}
+
+fn evaluate(expr: &str) -> Result<f64, CalcError> {
+    let tokens = tokenize(expr)?;
+    let postfix = to_postfix(&tokens)?;
+    evaluate_postfix(&postfix)
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{evaluate, CalcError};
+
+    #[test]
+    fn single_number() {
+        assert_eq!(evaluate("42"), Ok(42.0));
+        assert_eq!(evaluate("  3.5 "), Ok(3.5));
+    }
+
+    #[test]
+    fn simple_operations() {
+        assert_eq!(evaluate("1 + 2"), Ok(3.0));
+        assert_eq!(evaluate("5 - 8"), Ok(-3.0));
+        assert_eq!(evaluate("4 * 2.5"), Ok(10.0));
+        assert_eq!(evaluate("9 / 2"), Ok(4.5));
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(evaluate("2 + 3 * 4"), Ok(14.0));
+        assert_eq!(evaluate("2 * 3 + 4"), Ok(10.0));
+        assert_eq!(evaluate("10 - 6 / 2"), Ok(7.0));
+        assert_eq!(evaluate("1 + 2 * 3 - 4 / 2"), Ok(5.0));
+    }
+
+    #[test]
+    fn left_associativity() {
+        assert_eq!(evaluate("10 - 4 - 3"), Ok(3.0));
+        assert_eq!(evaluate("64 / 4 / 2"), Ok(8.0));
+    }
+
+    #[test]
+    fn parentheses() {
+        assert_eq!(evaluate("(2 + 3) * 4"), Ok(20.0));
+        assert_eq!(evaluate("2 * (3 + (4 - 1)) / 3"), Ok(4.0));
+        assert_eq!(evaluate("((((7))))"), Ok(7.0));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(evaluate("-3"), Ok(-3.0));
+        assert_eq!(evaluate("--3"), Ok(3.0));
+        assert_eq!(evaluate("2 * -3"), Ok(-6.0));
+        assert_eq!(evaluate("-(1 + 2) * 4"), Ok(-12.0));
+        assert_eq!(evaluate("4 - -2"), Ok(6.0));
+    }
+
+    #[test]
+    fn mismatched_parentheses() {
+        assert_eq!(evaluate("(1 + 2"), Err(CalcError::MismatchedParentheses));
+        assert_eq!(evaluate("1 + 2)"), Err(CalcError::MismatchedParentheses));
+        assert_eq!(evaluate(")("), Err(CalcError::MismatchedParentheses));
+    }
+
+    #[test]
+    fn unknown_token() {
+        assert_eq!(evaluate("2 ^ 3"), Err(CalcError::UnknownToken('^')));
+        assert_eq!(evaluate("x + 1"), Err(CalcError::UnknownToken('x')));
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(evaluate("1 / 0"), Err(CalcError::DivisionByZero));
+        assert_eq!(evaluate("1 / (2 - 2)"), Err(CalcError::DivisionByZero));
+    }
+
+    #[test]
+    fn invalid_expression() {
+        assert_eq!(evaluate(""), Err(CalcError::InvalidExpression));
+        assert_eq!(evaluate("1 +"), Err(CalcError::InvalidExpression));
+        assert_eq!(evaluate("1 2"), Err(CalcError::InvalidExpression));
+        assert_eq!(evaluate("1..2"), Err(CalcError::InvalidExpression));
+    }
+}
@@ -0,0 +1,146 @@
+//! Run this file with `cargo test --test simple_cipher`.
+
+//! TODO: Implement a simple substitution cipher driven by a key string.
+//! Every letter of the key describes a shift: `a` shifts by 0, `b` by 1, ..., `z` by 25.
+//! The key is cycled so that it covers the whole text, e.g. encoding `"aaaa"` with the key `"ab"`
+//! produces `"abab"`.
+//!
+//! Implement two functions:
+//! - `encode`: shifts every letter of the plaintext forward by the matching key letter.
+//! - `decode`: shifts every letter of the ciphertext backward, undoing `encode`.
+//!
+//! The key must not be empty and may only contain ASCII letters. The text may only contain ASCII
+//! letters as well, the case of every letter is preserved. Report invalid input using the
+//! `CipherError` enum.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq)]
+enum CipherError {
+    EmptyKey,
+    InvalidKeyCharacter(char),
+    InvalidCharacter(char),
+}
+
+impl Display for CipherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for CipherError {}
+
+fn key_shifts(key: &str) -> Result<Vec<u8>, CipherError> {
+    if key.is_empty() {
+        return Err(CipherError::EmptyKey);
+    }
+
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphabetic() {
+                Ok(c.to_ascii_lowercase() as u8 - b'a')
+            } else {
+                Err(CipherError::InvalidKeyCharacter(c))
+            }
+        })
+        .collect()
+}
+
+fn shift_text(key: &str, text: &str, forward: bool) -> Result<String, CipherError> {
+    let shifts = key_shifts(key)?;
+
+    text.chars()
+        .zip(shifts.iter().cycle())
+        .map(|(c, shift)| {
+            if !c.is_ascii_alphabetic() {
+                return Err(CipherError::InvalidCharacter(c));
+            }
+
+            let base = if c.is_ascii_lowercase() { b'a' } else { b'A' };
+            let offset = c as u8 - base;
+            let shifted = if forward {
+                (offset + shift) % 26
+            } else {
+                (offset + 26 - shift) % 26
+            };
+
+            Ok((base + shifted) as char)
+        })
+        .collect()
+}
+
+fn encode(key: &str, plaintext: &str) -> Result<String, CipherError> {
+    shift_text(key, plaintext, true)
+}
+
+fn decode(key: &str, ciphertext: &str) -> Result<String, CipherError> {
+    shift_text(key, ciphertext, false)
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode, CipherError};
+
+    #[test]
+    fn encode_with_identity_key() {
+        assert_eq!(encode("a", "hello"), Ok("hello".to_string()));
+    }
+
+    #[test]
+    fn encode_cycles_key() {
+        assert_eq!(encode("ab", "aaaa"), Ok("abab".to_string()));
+        assert_eq!(encode("abc", "iamapandabear"), Ok("iboaqcnecbfcr".to_string()));
+    }
+
+    #[test]
+    fn encode_wraps_around() {
+        assert_eq!(encode("b", "xyz"), Ok("yza".to_string()));
+    }
+
+    #[test]
+    fn encode_preserves_case() {
+        assert_eq!(encode("b", "HeLLo"), Ok("IfMMp".to_string()));
+        assert_eq!(encode("B", "hello"), Ok("ifmmp".to_string()));
+    }
+
+    #[test]
+    fn decode_reverses_shift() {
+        assert_eq!(decode("abc", "iboaqcnecbfcr"), Ok("iamapandabear".to_string()));
+        assert_eq!(decode("b", "yza"), Ok("xyz".to_string()));
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = "dduqgwkjwqheeqzxmmgjyoqnvspvtmxj";
+        let plaintext = "TheQuickBrownFoxJumpsOverTheLazyDog";
+        let ciphertext = encode(key, plaintext).unwrap();
+        assert_ne!(ciphertext, plaintext);
+        assert_eq!(decode(key, &ciphertext), Ok(plaintext.to_string()));
+    }
+
+    #[test]
+    fn empty_text() {
+        assert_eq!(encode("abc", ""), Ok(String::new()));
+        assert_eq!(decode("abc", ""), Ok(String::new()));
+    }
+
+    #[test]
+    fn empty_key() {
+        assert_eq!(encode("", "hello"), Err(CipherError::EmptyKey));
+        assert_eq!(decode("", "hello"), Err(CipherError::EmptyKey));
+    }
+
+    #[test]
+    fn non_alphabetic_key() {
+        assert_eq!(encode("ab1", "hello"), Err(CipherError::InvalidKeyCharacter('1')));
+        assert_eq!(decode("a b", "hello"), Err(CipherError::InvalidKeyCharacter(' ')));
+    }
+
+    #[test]
+    fn non_alphabetic_text() {
+        assert_eq!(encode("abc", "hello world"), Err(CipherError::InvalidCharacter(' ')));
+        assert_eq!(decode("abc", "héllo"), Err(CipherError::InvalidCharacter('é')));
+    }
+}
@@ -0,0 +1,251 @@
+//! Run this file with `cargo test --test simple_hash_map`.
+
+//! TODO: Implement a hash map from scratch, using
+//! [open addressing](https://en.wikipedia.org/wiki/Open_addressing) with linear probing.
+//!
+//! All entries are stored directly in a single vector of slots. A key is placed into the slot
+//! given by its hash modulo the number of slots, or if that slot is taken, into the next free
+//! slot after it (wrapping around at the end).
+//!
+//! - `new`: creates a map with room for `capacity` slots (at least one).
+//! - `insert`: inserts a key with a value, replacing the value if the key is already present.
+//! - `get`: returns the value stored for a key.
+//! - `remove`: removes a key and returns its value.
+//! - `len`: returns the number of stored keys.
+//!
+//! Removing an entry cannot simply empty its slot, because that would break the probe sequence
+//! of the keys placed after it. Instead, mark the slot as deleted: lookups skip over it, but new
+//! entries may reuse it.
+//!
+//! Once more than 75% of the slots are in use (including deleted ones), insert all entries again
+//! into fresh slots, doubling their number unless most of the used slots were deleted ones.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[derive(Clone)]
+enum Slot<K, V> {
+    Empty,
+    Deleted,
+    Occupied(K, V),
+}
+
+struct SimpleHashMap<K: Hash + Eq + Clone, V: Clone> {
+    slots: Vec<Slot<K, V>>,
+    len: usize,
+    /// Number of `Slot::Deleted` slots, which count towards the load factor.
+    deleted: usize,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> SimpleHashMap<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            slots: vec![Slot::Empty; capacity.max(1)],
+            len: 0,
+            deleted: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn home_slot(&self, key: &K) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() % self.slots.len() as u64) as usize
+    }
+
+    /// Returns the index of the slot holding `key`, if it is present.
+    fn find(&self, key: &K) -> Option<usize> {
+        let start = self.home_slot(key);
+        for offset in 0..self.slots.len() {
+            let index = (start + offset) % self.slots.len();
+            match &self.slots[index] {
+                Slot::Empty => return None,
+                Slot::Occupied(k, _) if k == key => return Some(index),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if let Some(index) = self.find(&key) {
+            self.slots[index] = Slot::Occupied(key, value);
+            return;
+        }
+
+        if (self.len + self.deleted + 1) * 4 > self.slots.len() * 3 {
+            // If mostly deleted slots fill up the map, dropping them is enough to make room.
+            let grow = (self.len + 1) * 2 > self.slots.len();
+            let capacity = if grow { self.slots.len() * 2 } else { self.slots.len() };
+            self.resize(capacity);
+        }
+
+        // The key is not present, so the first slot that is not occupied can be used.
+        let start = self.home_slot(&key);
+        for offset in 0..self.slots.len() {
+            let index = (start + offset) % self.slots.len();
+            match self.slots[index] {
+                Slot::Occupied(..) => {}
+                Slot::Deleted => {
+                    self.deleted -= 1;
+                    self.slots[index] = Slot::Occupied(key, value);
+                    self.len += 1;
+                    return;
+                }
+                Slot::Empty => {
+                    self.slots[index] = Slot::Occupied(key, value);
+                    self.len += 1;
+                    return;
+                }
+            }
+        }
+
+        unreachable!("the map is resized before it can fill up");
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        match &self.slots[self.find(key)?] {
+            Slot::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.find(key)?;
+        match std::mem::replace(&mut self.slots[index], Slot::Deleted) {
+            Slot::Occupied(_, value) => {
+                self.len -= 1;
+                self.deleted += 1;
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves all entries into `capacity` fresh slots, dropping the deleted markers.
+    fn resize(&mut self, capacity: usize) {
+        let old_slots = std::mem::replace(&mut self.slots, vec![Slot::Empty; capacity]);
+        self.len = 0;
+        self.deleted = 0;
+
+        for slot in old_slots {
+            if let Slot::Occupied(key, value) = slot {
+                self.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::SimpleHashMap;
+
+    #[test]
+    fn insert_and_get() {
+        let mut map = SimpleHashMap::new(8);
+        assert!(map.is_empty());
+        map.insert("one", 1);
+        map.insert("two", 2);
+        assert_eq!(map.get(&"one"), Some(&1));
+        assert_eq!(map.get(&"two"), Some(&2));
+        assert_eq!(map.get(&"three"), None);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn insert_replaces() {
+        let mut map = SimpleHashMap::new(8);
+        map.insert('a', 1);
+        map.insert('a', 2);
+        assert_eq!(map.get(&'a'), Some(&2));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn remove_keys() {
+        let mut map = SimpleHashMap::new(8);
+        map.insert(1, "a");
+        map.insert(2, "b");
+        assert_eq!(map.remove(&1), Some("a"));
+        assert_eq!(map.remove(&1), None);
+        assert_eq!(map.remove(&3), None);
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get(&2), Some(&"b"));
+        assert_eq!(map.len(), 1);
+
+        map.insert(1, "c");
+        assert_eq!(map.get(&1), Some(&"c"));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn colliding_keys_survive_removal() {
+        // With a single slot to start with, probe sequences overlap constantly.
+        let mut map = SimpleHashMap::new(1);
+        for key in 0..20 {
+            map.insert(key, key * 10);
+        }
+        for key in (0..20).step_by(2) {
+            assert_eq!(map.remove(&key), Some(key * 10));
+        }
+        for key in 0..20 {
+            let expected = (key % 2 == 1).then_some(key * 10);
+            assert_eq!(map.get(&key).copied(), expected, "{key}");
+        }
+    }
+
+    #[test]
+    fn resizes_at_load_factor() {
+        let mut map = SimpleHashMap::new(4);
+        for key in 0..3 {
+            map.insert(key, ());
+        }
+        assert_eq!(map.capacity(), 4);
+        map.insert(3, ());
+        assert_eq!(map.capacity(), 8);
+    }
+
+    #[test]
+    fn works_after_resize() {
+        let mut map = SimpleHashMap::new(2);
+        for key in 0..1000u32 {
+            map.insert(key.to_string(), key);
+        }
+        assert_eq!(map.len(), 1000);
+        assert!(map.capacity() * 3 >= map.len() * 4);
+        for key in 0..1000u32 {
+            assert_eq!(map.get(&key.to_string()), Some(&key));
+        }
+    }
+
+    #[test]
+    fn deleted_slots_are_reused() {
+        let mut map = SimpleHashMap::new(16);
+        for _ in 0..100 {
+            map.insert(7, "x");
+            assert_eq!(map.remove(&7), Some("x"));
+        }
+        assert!(map.is_empty());
+        assert!(map.capacity() <= 32);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut map = SimpleHashMap::new(0);
+        assert_eq!(map.get(&1), None);
+        map.insert(1, 1);
+        assert_eq!(map.get(&1), Some(&1));
+    }
+}
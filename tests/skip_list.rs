@@ -0,0 +1,262 @@
+//! Run this file with `cargo test --test skip_list`.
+
+//! TODO: Implement a [skip list](https://en.wikipedia.org/wiki/Skip_list), a probabilistic data
+//! structure that keeps its values sorted and supports searching, inserting and removing in
+//! O(log n) time on average.
+//!
+//! A skip list is a sorted linked list with additional "express lanes": every node is part of the
+//! bottom level, and with probability 1/2 it is also part of the level above it, and so on.
+//! Searching starts at the highest level and moves down a level whenever the next node would
+//! overshoot the searched value.
+//!
+//! `SkipList` behaves like a set, inserting a value that is already present does nothing.
+//! Levels are picked using a seeded random number generator, so that the structure of the list
+//! (and therefore the tests) are deterministic.
+//!
+//! Hint: instead of fighting the borrow checker with pointers, store the nodes in a `Vec` and link
+//! them using indices.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const MAX_LEVEL: usize = 16;
+const DEFAULT_SEED: u64 = 0x5eed;
+
+struct Node<T> {
+    /// `None` only for the head node and for removed nodes.
+    value: Option<T>,
+    /// Index of the next node on every level this node is part of.
+    next: Vec<Option<usize>>,
+}
+
+struct SkipList<T: Ord> {
+    /// `nodes[0]` is the head, which is part of every level and holds no value.
+    nodes: Vec<Node<T>>,
+    /// Indices of removed nodes, which can be reused by later insertions.
+    free: Vec<usize>,
+    rng: StdRng,
+}
+
+impl<T: Ord> SkipList<T> {
+    fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    fn with_seed(seed: u64) -> Self {
+        Self {
+            nodes: vec![Node {
+                value: None,
+                next: vec![None; MAX_LEVEL],
+            }],
+            free: vec![],
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn value(&self, idx: usize) -> &T {
+        self.nodes[idx].value.as_ref().unwrap()
+    }
+
+    /// Returns, for every level, the index of the last node whose value is smaller than `value`,
+    /// together with the number of links followed to find them.
+    fn predecessors(&self, value: &T) -> ([usize; MAX_LEVEL], usize) {
+        let mut predecessors = [0; MAX_LEVEL];
+        let mut current = 0;
+        let mut steps = 0;
+
+        for level in (0..MAX_LEVEL).rev() {
+            while let Some(next) = self.nodes[current].next[level] {
+                if self.value(next) >= value {
+                    break;
+                }
+                current = next;
+                steps += 1;
+            }
+            predecessors[level] = current;
+        }
+
+        (predecessors, steps)
+    }
+
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && self.rng.random_bool(0.5) {
+            level += 1;
+        }
+        level
+    }
+
+    fn insert(&mut self, value: T) {
+        let (predecessors, _) = self.predecessors(&value);
+        if let Some(next) = self.nodes[predecessors[0]].next[0]
+            && *self.value(next) == value
+        {
+            return;
+        }
+
+        let level = self.random_level();
+        let next = (0..level)
+            .map(|l| self.nodes[predecessors[l]].next[l])
+            .collect();
+        let node = Node {
+            value: Some(value),
+            next,
+        };
+
+        let idx = match self.free.pop() {
+            Some(idx) => {
+                self.nodes[idx] = node;
+                idx
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+
+        for (l, &predecessor) in predecessors.iter().enumerate().take(level) {
+            self.nodes[predecessor].next[l] = Some(idx);
+        }
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        let (predecessors, _) = self.predecessors(value);
+        match self.nodes[predecessors[0]].next[0] {
+            Some(next) => self.value(next) == value,
+            None => false,
+        }
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        let (predecessors, _) = self.predecessors(value);
+        let target = match self.nodes[predecessors[0]].next[0] {
+            Some(next) if self.value(next) == value => next,
+            _ => return false,
+        };
+
+        let next = std::mem::take(&mut self.nodes[target].next);
+        for (l, &successor) in next.iter().enumerate() {
+            self.nodes[predecessors[l]].next[l] = successor;
+        }
+        self.nodes[target].value = None;
+        self.free.push(target);
+        true
+    }
+
+    /// Number of links followed when searching for `value`.
+    fn search_steps(&self, value: &T) -> usize {
+        self.predecessors(value).1
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut current = self.nodes[0].next[0];
+        std::iter::from_fn(move || {
+            let idx = current?;
+            current = self.nodes[idx].next[0];
+            Some(self.value(idx))
+        })
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::SkipList;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn empty_list() {
+        let list: SkipList<i32> = SkipList::new();
+        assert_eq!(list.iter().count(), 0);
+        assert!(!list.contains(&1));
+    }
+
+    #[test]
+    fn insert_and_contains() {
+        let mut list = SkipList::new();
+        list.insert(5);
+        list.insert(1);
+        list.insert(3);
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(list.contains(&5));
+        assert!(!list.contains(&2));
+        assert!(!list.contains(&6));
+    }
+
+    #[test]
+    fn iterates_in_order() {
+        let mut list = SkipList::new();
+        for value in [8, 3, 9, 1, 4, 7] {
+            list.insert(value);
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3, 4, 7, 8, 9]);
+    }
+
+    #[test]
+    fn ignores_duplicates() {
+        let mut list = SkipList::new();
+        list.insert("b");
+        list.insert("a");
+        list.insert("b");
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn remove_values() {
+        let mut list = SkipList::new();
+        for value in 0..10 {
+            list.insert(value);
+        }
+        assert!(list.remove(&0));
+        assert!(list.remove(&5));
+        assert!(list.remove(&9));
+        assert!(!list.remove(&5));
+        assert!(!list.remove(&42));
+        assert!(!list.contains(&5));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 6, 7, 8]);
+
+        list.insert(5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn random_insertions_and_removals() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut list = SkipList::with_seed(7);
+        let mut expected = BTreeSet::new();
+
+        for _ in 0..5000 {
+            let value = rng.random_range(0..500);
+            if rng.random_bool(0.7) {
+                list.insert(value);
+                expected.insert(value);
+            } else {
+                assert_eq!(list.remove(&value), expected.remove(&value));
+            }
+        }
+
+        assert!(list.iter().eq(expected.iter()));
+        for value in 0..500 {
+            assert_eq!(list.contains(&value), expected.contains(&value));
+        }
+    }
+
+    #[test]
+    fn logarithmic_search() {
+        let n = 4096;
+        let mut list = SkipList::new();
+        for value in 0..n {
+            list.insert(value);
+        }
+
+        let total_steps: usize = (0..n).map(|value| list.search_steps(&value)).sum();
+        let average = total_steps as f64 / n as f64;
+        let log_n = (n as f64).log2();
+
+        // A plain linked list would need n / 2 = 2048 steps on average.
+        assert!(average < 3.0 * log_n, "average search steps {average}");
+    }
+}
@@ -0,0 +1,143 @@
+//! Run this file with `cargo test --test sliding_window_max`.
+
+//! TODO: Implement a function called `sliding_window_max`, which returns the maximum of every
+//! window of `k` consecutive values, from left to right. For example, the windows of size 3 of
+//! `[1, 3, -1, -3, 5, 3, 6, 7]` have the maxima `[3, 3, 5, 5, 6, 7]`.
+//!
+//! Return `WindowError::EmptyWindow` if `k` is zero and `WindowError::WindowTooLarge` if `k` is
+//! larger than the number of values.
+//!
+//! Recomputing the maximum of every window takes O(n * k) time. Instead, solve it in O(n) time
+//! by keeping a `VecDeque` of indices whose values are decreasing: the front is always the index
+//! of the current maximum, and an index is dropped once it leaves the window or once a larger
+//! value arrives behind it.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq)]
+enum WindowError {
+    EmptyWindow,
+    WindowTooLarge { k: usize, len: usize },
+}
+
+impl Display for WindowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for WindowError {}
+
+fn sliding_window_max(values: &[i64], k: usize) -> Result<Vec<i64>, WindowError> {
+    if k == 0 {
+        return Err(WindowError::EmptyWindow);
+    }
+    if k > values.len() {
+        return Err(WindowError::WindowTooLarge {
+            k,
+            len: values.len(),
+        });
+    }
+
+    let mut candidates: VecDeque<usize> = VecDeque::new();
+    let mut maxima = Vec::with_capacity(values.len() - k + 1);
+
+    for (index, &value) in values.iter().enumerate() {
+        while candidates.back().is_some_and(|&last| values[last] <= value) {
+            candidates.pop_back();
+        }
+        candidates.push_back(index);
+
+        if candidates.front().is_some_and(|&first| first + k <= index) {
+            candidates.pop_front();
+        }
+
+        if index + 1 >= k {
+            maxima.push(values[candidates[0]]);
+        }
+    }
+
+    Ok(maxima)
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{sliding_window_max, WindowError};
+
+    fn naive_window_max(values: &[i64], k: usize) -> Vec<i64> {
+        values
+            .windows(k)
+            .map(|window| *window.iter().max().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            sliding_window_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+            Ok(vec![3, 3, 5, 5, 6, 7])
+        );
+    }
+
+    #[test]
+    fn window_of_one() {
+        assert_eq!(sliding_window_max(&[4, -2, 7], 1), Ok(vec![4, -2, 7]));
+    }
+
+    #[test]
+    fn window_of_everything() {
+        assert_eq!(sliding_window_max(&[4, -2, 7, 1], 4), Ok(vec![7]));
+    }
+
+    #[test]
+    fn monotonic_values() {
+        assert_eq!(sliding_window_max(&[1, 2, 3, 4, 5], 2), Ok(vec![2, 3, 4, 5]));
+        assert_eq!(sliding_window_max(&[5, 4, 3, 2, 1], 2), Ok(vec![5, 4, 3, 2]));
+        assert_eq!(sliding_window_max(&[3, 3, 3, 3], 3), Ok(vec![3, 3]));
+    }
+
+    #[test]
+    fn output_length() {
+        let values: Vec<i64> = (0..50).collect();
+        for k in 1..=values.len() {
+            assert_eq!(
+                sliding_window_max(&values, k).unwrap().len(),
+                values.len() - k + 1
+            );
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        // A deterministic, but irregular sequence.
+        let values: Vec<i64> = (0..200i64).map(|i| (i * 7919) % 101 - 50).collect();
+        for k in 1..=values.len() {
+            assert_eq!(
+                sliding_window_max(&values, k),
+                Ok(naive_window_max(&values, k)),
+                "k = {k}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_window() {
+        assert_eq!(sliding_window_max(&[1, 2, 3], 0), Err(WindowError::EmptyWindow));
+        assert_eq!(sliding_window_max(&[], 0), Err(WindowError::EmptyWindow));
+    }
+
+    #[test]
+    fn window_too_large() {
+        assert_eq!(
+            sliding_window_max(&[1, 2, 3], 4),
+            Err(WindowError::WindowTooLarge { k: 4, len: 3 })
+        );
+        assert_eq!(
+            sliding_window_max(&[], 1),
+            Err(WindowError::WindowTooLarge { k: 1, len: 0 })
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Run this file with `cargo test --test sparse_table`.
+
+//! TODO: Implement a [sparse table](https://en.wikipedia.org/wiki/Range_minimum_query#Solution_using_constant_time_and_linearithmic_space)
+//! that answers range minimum queries over a fixed list of values in O(1), after an O(n log n)
+//! build.
+//!
+//! - `build`: precomputes the table from the values.
+//! - `query_min`: returns the minimum of the values at indices `lo..=hi` (both inclusive).
+//!
+//! Queries with `lo > hi` or indices outside of the values should panic.
+//!
+//! Hint: let `table[k][i]` be the minimum of the `2^k` values starting at index `i`. Each level
+//! can be computed from the previous one, since a block of `2^k` values consists of two blocks of
+//! `2^(k - 1)` values. Any range is covered by two (possibly overlapping) blocks of the same size:
+//! one starting at `lo` and one ending at `hi`. Overlapping does not matter for the minimum.
+//! Precompute the base-2 logarithms of all lengths, so that a query does not need to compute one.
+
+struct SparseTable {
+    /// `table[k][i]` is the minimum of `values[i..i + 2^k]`.
+    table: Vec<Vec<i64>>,
+    /// `log[len]` is the base-2 logarithm of `len`, rounded down.
+    log: Vec<usize>,
+}
+
+impl SparseTable {
+    fn build(values: &[i64]) -> Self {
+        let n = values.len();
+        let mut log = vec![0; n + 1];
+        for len in 2..=n {
+            log[len] = log[len / 2] + 1;
+        }
+
+        let mut table = vec![values.to_vec()];
+        let mut width = 1;
+        while 2 * width <= n {
+            let previous = table.last().unwrap();
+            let level = (0..=n - 2 * width)
+                .map(|i| previous[i].min(previous[i + width]))
+                .collect();
+            table.push(level);
+            width *= 2;
+        }
+
+        Self { table, log }
+    }
+
+    fn query_min(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi, "lo must not be larger than hi");
+        assert!(hi < self.table[0].len(), "index {hi} out of bounds");
+
+        let k = self.log[hi - lo + 1];
+        let level = &self.table[k];
+        level[lo].min(level[hi + 1 - (1 << k)])
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::SparseTable;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn small_example() {
+        let table = SparseTable::build(&[5, 2, 4, 7, 1, 3, 6]);
+        assert_eq!(table.query_min(0, 6), 1);
+        assert_eq!(table.query_min(0, 3), 2);
+        assert_eq!(table.query_min(2, 3), 4);
+        assert_eq!(table.query_min(5, 6), 3);
+    }
+
+    #[test]
+    fn single_element_queries() {
+        let values = [9, -4, 0, 12];
+        let table = SparseTable::build(&values);
+        for (i, &value) in values.iter().enumerate() {
+            assert_eq!(table.query_min(i, i), value);
+        }
+        assert_eq!(SparseTable::build(&[42]).query_min(0, 0), 42);
+    }
+
+    #[test]
+    fn logarithms() {
+        let table = SparseTable::build(&[0; 9]);
+        assert_eq!(table.log, vec![0, 0, 1, 1, 2, 2, 2, 2, 3, 3]);
+        assert_eq!(table.table.len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn query_out_of_bounds() {
+        SparseTable::build(&[1, 2, 3]).query_min(1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "lo must not be larger than hi")]
+    fn query_reversed() {
+        SparseTable::build(&[1, 2, 3]).query_min(2, 1);
+    }
+
+    #[test]
+    fn matches_brute_force_on_all_subarrays() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let values: Vec<i64> = (0..100).map(|_| rng.random_range(-1000..1000)).collect();
+        let table = SparseTable::build(&values);
+
+        for lo in 0..values.len() {
+            for hi in lo..values.len() {
+                let expected = *values[lo..=hi].iter().min().unwrap();
+                assert_eq!(table.query_min(lo, hi), expected, "{lo}..={hi}");
+            }
+        }
+    }
+}
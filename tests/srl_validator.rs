@@ -35,7 +35,10 @@ mod srl {
         EmptyProtocol,
         EmptyAddress,
         InvalidCharacterInAddress(char),
-        InvalidCharacterInProtocol(char)
+        InvalidCharacterInProtocol(char),
+        LeadingHyphenInAddress,
+        TrailingHyphenInAddress,
+        DoubleHyphenInAddress,
     }
 
     impl Display for SRLValidationError {
@@ -46,19 +49,99 @@ mod srl {
 
     impl Error for SRLValidationError {}
 
+    /// A validation error together with the input that caused it.
     #[derive(Debug, Eq, PartialEq)]
+    pub struct VerboseSrlError {
+        pub kind: SRLValidationError,
+        pub input: String,
+    }
+
+    impl Display for VerboseSrlError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} in SRL {:?}", self.kind, self.input)
+        }
+    }
+
+    impl Error for VerboseSrlError {}
+
+    /// Characters permitted in the address part of a SRL.
+    pub fn allowed_address_chars() -> impl Iterator<Item = char> {
+        'a'..='z'
+    }
+
+    /// Characters permitted in the protocol part of a SRL.
+    pub fn allowed_protocol_chars() -> impl Iterator<Item = char> {
+        'a'..='z'
+    }
+
+    /// A regex character class matching exactly `chars`.
+    fn char_class(chars: impl Iterator<Item = char>) -> String {
+        let chars: String = chars.map(|c| regex::escape(&c.to_string())).collect();
+        format!("[{chars}]")
+    }
+
+    /// Opt-in relaxations of the SRL format, the default options accept exactly what `SRL::new`
+    /// accepts.
+    #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+    pub struct SrlOptions {
+        /// Permits single `-` characters between the letters of the address, like `foo-bar`.
+        pub allow_hyphen_in_address: bool,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
     pub struct SRL {
         address: String,
         protocol: Option<String>,
     }
 
+    fn validate_address(address: &str) -> Result<(), SRLValidationError> {
+        if address.is_empty() {
+            return Err(SRLValidationError::EmptyAddress);
+        }
+
+        match address.chars().find(|c| !allowed_address_chars().any(|a| a == *c)) {
+            Some(c) => Err(SRLValidationError::InvalidCharacterInAddress(c)),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_protocol(protocol: &str) -> Result<(), SRLValidationError> {
+        if protocol.is_empty() {
+            return Err(SRLValidationError::EmptyProtocol);
+        }
+
+        match protocol.chars().find(|c| !allowed_protocol_chars().any(|a| a == *c)) {
+            Some(c) => Err(SRLValidationError::InvalidCharacterInProtocol(c)),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether `text` matches the glob `pattern`, in which `*` matches any run of lowercase
+    /// letters, including an empty one.
+    fn glob_matches(pattern: &[char], text: &[char]) -> bool {
+        match pattern.split_first() {
+            None => text.is_empty(),
+            Some(('*', rest)) => {
+                // Try every run of letters for the star, from the shortest one.
+                let letters = text.iter().take_while(|c| c.is_ascii_lowercase()).count();
+                (0..=letters).any(|skip| glob_matches(rest, &text[skip..]))
+            }
+            Some((c, rest)) => text.first() == Some(c) && glob_matches(rest, &text[1..]),
+        }
+    }
+
     impl SRL {
         pub fn new(full_address: &str) -> Result<Self, SRLValidationError> {
             if full_address.is_empty() {
                 return Err(SRLValidationError::EmptyAddress);
             }
 
-            let regex = Regex::new(r"(?<protocol>[a-z]*)(?<invalidp>\w*)(?<delimeter>://)*(?<address>[a-z]*)(?<invalida>.*)").unwrap();
+            let regex = Regex::new(&format!(
+                r"(?<protocol>{}*)(?<invalidp>\w*)(?<delimeter>://)*(?<address>{}*)(?<invalida>.*)",
+                char_class(allowed_protocol_chars()),
+                char_class(allowed_address_chars()),
+            ))
+            .unwrap();
             let captures = regex.captures(full_address).unwrap();
 
             let delimeter = match captures.name("delimeter") {
@@ -120,6 +203,74 @@ mod srl {
             }
         }
 
+        pub fn new_with_options(
+            full_address: &str,
+            options: SrlOptions,
+        ) -> Result<Self, SRLValidationError> {
+            if !options.allow_hyphen_in_address {
+                return Self::new(full_address);
+            }
+
+            let (protocol, address) = match full_address.split_once("://") {
+                Some((protocol, address)) => (Some(protocol), address),
+                None => (None, full_address),
+            };
+            if let Some(protocol) = protocol {
+                validate_protocol(protocol)?;
+            }
+
+            if address.starts_with('-') {
+                return Err(SRLValidationError::LeadingHyphenInAddress);
+            }
+            if address.ends_with('-') {
+                return Err(SRLValidationError::TrailingHyphenInAddress);
+            }
+            if address.contains("--") {
+                return Err(SRLValidationError::DoubleHyphenInAddress);
+            }
+
+            // Every hyphen is now surrounded by other characters, which `new` validates.
+            let without_hyphens = address.replace('-', "");
+            let srl = match protocol {
+                Some(protocol) => Self::new(&format!("{protocol}://{without_hyphens}"))?,
+                None => Self::new(&without_hyphens)?,
+            };
+
+            Ok(Self {
+                address: address.to_string(),
+                ..srl
+            })
+        }
+
+        pub fn new_verbose(full_address: &str) -> Result<Self, VerboseSrlError> {
+            Self::new(full_address).map_err(|kind| VerboseSrlError {
+                kind,
+                input: full_address.to_string(),
+            })
+        }
+
+        /// Returns a copy of this SRL with the address replaced by `new`.
+        pub fn with_address(&self, new: &str) -> Result<SRL, SRLValidationError> {
+            validate_address(new)?;
+
+            Ok(Self {
+                address: new.to_string(),
+                protocol: self.protocol.clone(),
+            })
+        }
+
+        /// Returns a copy of this SRL with the protocol replaced by `new`, `None` removes it.
+        pub fn with_protocol(&self, new: Option<&str>) -> Result<SRL, SRLValidationError> {
+            if let Some(protocol) = new {
+                validate_protocol(protocol)?;
+            }
+
+            Ok(Self {
+                address: self.address.clone(),
+                protocol: new.map(str::to_string),
+            })
+        }
+
         pub fn get_protocol(&self) -> Option<&str> {
             match &self.protocol {
                 Some(protocol) => Some(&protocol),
@@ -130,13 +281,50 @@ mod srl {
         pub fn get_address(&self) -> &str {
             &self.address
         }
+
+        /// Returns the protocol, or `default` if the SRL has none.
+        pub fn protocol_or<'a>(&'a self, default: &'a str) -> &'a str {
+            self.get_protocol().unwrap_or(default)
+        }
+
+        /// Compares only the addresses, ignoring the protocols. `==` compares both.
+        pub fn eq_address(&self, other: &SRL) -> bool {
+            self.address == other.address
+        }
+
+        /// Matches the SRL against a pattern of the form `[<protocol>://]<address>`. In the
+        /// address, `*` matches any run of lowercase letters. The protocol has to match exactly if
+        /// the pattern has one, otherwise any protocol matches.
+        pub fn matches_pattern(&self, pattern: &str) -> bool {
+            let address_pattern = match pattern.split_once("://") {
+                Some((protocol, address)) => {
+                    if self.get_protocol() != Some(protocol) {
+                        return false;
+                    }
+                    address
+                }
+                None => pattern,
+            };
+
+            let pattern: Vec<char> = address_pattern.chars().collect();
+            let address: Vec<char> = self.address.chars().collect();
+            glob_matches(&pattern, &address)
+        }
+
+        /// Returns the protocol and the address at once, e.g. for matching on both.
+        pub fn components(&self) -> (Option<&str>, &str) {
+            (self.get_protocol(), self.get_address())
+        }
     }
 }
 
 /// Below you can find a set of unit tests.
 #[cfg(test)]
 mod tests {
-    use super::srl::{SRLValidationError, SRL};
+    use super::srl::{
+        allowed_address_chars, allowed_protocol_chars, SRLValidationError, SrlOptions,
+        VerboseSrlError, SRL,
+    };
 
     #[test]
     fn empty_address() {
@@ -230,4 +418,196 @@ mod tests {
         assert_eq!(srl.get_protocol(), Some("bar"));
         assert_eq!(srl.get_address(), "foobar");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn allowed_chars() {
+        let address_chars: Vec<char> = allowed_address_chars().collect();
+        assert_eq!(address_chars.len(), 26);
+        assert_eq!(address_chars.first(), Some(&'a'));
+        assert_eq!(address_chars.last(), Some(&'z'));
+
+        assert_eq!(allowed_protocol_chars().count(), 26);
+        assert!(allowed_protocol_chars().all(|c| c.is_ascii_lowercase()));
+    }
+
+    #[test]
+    fn verbose_error_echoes_input() {
+        let error = SRL::new_verbose("abc://fo1o").unwrap_err();
+        assert_eq!(
+            error,
+            VerboseSrlError {
+                kind: SRLValidationError::InvalidCharacterInAddress('1'),
+                input: "abc://fo1o".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "InvalidCharacterInAddress('1') in SRL \"abc://fo1o\""
+        );
+    }
+
+    #[test]
+    fn verbose_success() {
+        assert_eq!(SRL::new_verbose("bar://foo"), Ok(SRL::new("bar://foo").unwrap()));
+    }
+
+    #[test]
+    fn with_address() {
+        let srl = SRL::new("http://foo").unwrap();
+        let swapped = srl.with_address("bar").unwrap();
+        assert_eq!(swapped, SRL::new("http://bar").unwrap());
+        assert_eq!(srl.get_address(), "foo");
+
+        assert_eq!(srl.with_address(""), Err(SRLValidationError::EmptyAddress));
+        assert_eq!(
+            srl.with_address("baz://qux"),
+            Err(SRLValidationError::InvalidCharacterInAddress(':'))
+        );
+    }
+
+    #[test]
+    fn with_protocol() {
+        let srl = SRL::new("http://foo").unwrap();
+        assert_eq!(srl.with_protocol(None).unwrap(), SRL::new("foo").unwrap());
+        assert_eq!(
+            srl.with_protocol(Some("ftp")).unwrap(),
+            SRL::new("ftp://foo").unwrap()
+        );
+        assert_eq!(srl.with_protocol(Some("")), Err(SRLValidationError::EmptyProtocol));
+        assert_eq!(
+            srl.with_protocol(Some("ht1p")),
+            Err(SRLValidationError::InvalidCharacterInProtocol('1'))
+        );
+    }
+
+    #[test]
+    fn clone_srl() {
+        let srl = SRL::new("bar://foobar").unwrap();
+        assert_eq!(srl.clone(), srl);
+    }
+
+    #[test]
+    fn components() {
+        match SRL::new("http://foo").unwrap().components() {
+            (Some("http"), address) => assert_eq!(address, "foo"),
+            other => panic!("unexpected components {other:?}"),
+        }
+
+        let srl = SRL::new("foobar").unwrap();
+        let (protocol, address) = srl.components();
+        assert_eq!(protocol, None);
+        assert_eq!(address, "foobar");
+    }
+
+    #[test]
+    fn protocol_or_default() {
+        assert_eq!(SRL::new("foobar").unwrap().protocol_or("http"), "http");
+        assert_eq!(SRL::new("ftp://foobar").unwrap().protocol_or("http"), "ftp");
+    }
+
+    #[test]
+    fn eq_address() {
+        let http = SRL::new("http://foo").unwrap();
+        let ftp = SRL::new("ftp://foo").unwrap();
+        let bare = SRL::new("foo").unwrap();
+        assert!(http.eq_address(&ftp));
+        assert!(http.eq_address(&bare));
+        assert_ne!(http, ftp);
+        assert!(!http.eq_address(&SRL::new("http://bar").unwrap()));
+    }
+
+    #[test]
+    fn hyphen_disallowed_by_default() {
+        assert_eq!(
+            SRL::new("foo-bar"),
+            Err(SRLValidationError::InvalidCharacterInAddress('-'))
+        );
+        assert_eq!(
+            SRL::new_with_options("foo-bar", SrlOptions::default()),
+            Err(SRLValidationError::InvalidCharacterInAddress('-'))
+        );
+    }
+
+    #[test]
+    fn hyphenated_address() {
+        let options = SrlOptions {
+            allow_hyphen_in_address: true,
+        };
+
+        let srl = SRL::new_with_options("http://foo-bar-baz", options).unwrap();
+        assert_eq!(srl.components(), (Some("http"), "foo-bar-baz"));
+        let srl = SRL::new_with_options("foo-bar", options).unwrap();
+        assert_eq!(srl.components(), (None, "foo-bar"));
+        assert_eq!(
+            SRL::new_with_options("http://foo", options),
+            SRL::new("http://foo")
+        );
+    }
+
+    #[test]
+    fn invalid_hyphenated_address() {
+        let options = SrlOptions {
+            allow_hyphen_in_address: true,
+        };
+
+        assert_eq!(
+            SRL::new_with_options("-foo", options),
+            Err(SRLValidationError::LeadingHyphenInAddress)
+        );
+        assert_eq!(
+            SRL::new_with_options("http://foo-", options),
+            Err(SRLValidationError::TrailingHyphenInAddress)
+        );
+        assert_eq!(
+            SRL::new_with_options("foo--bar", options),
+            Err(SRLValidationError::DoubleHyphenInAddress)
+        );
+        assert_eq!(
+            SRL::new_with_options("foo-1", options),
+            Err(SRLValidationError::InvalidCharacterInAddress('1'))
+        );
+        assert_eq!(
+            SRL::new_with_options("ht-tp://foo", options),
+            Err(SRLValidationError::InvalidCharacterInProtocol('-'))
+        );
+        assert_eq!(
+            SRL::new_with_options("-", options),
+            Err(SRLValidationError::LeadingHyphenInAddress)
+        );
+    }
+
+    #[test]
+    fn matches_pattern() {
+        let srl = SRL::new("foobar").unwrap();
+        assert!(srl.matches_pattern("foo*"));
+        assert!(srl.matches_pattern("*bar"));
+        assert!(srl.matches_pattern("*"));
+        assert!(srl.matches_pattern("f*b*r"));
+        assert!(srl.matches_pattern("foobar*"));
+        assert!(srl.matches_pattern("foobar"));
+        assert!(!srl.matches_pattern("bar*"));
+        assert!(!srl.matches_pattern("*foo"));
+        assert!(!srl.matches_pattern("foo"));
+        assert!(!srl.matches_pattern(""));
+    }
+
+    #[test]
+    fn matches_pattern_with_protocol() {
+        let srl = SRL::new("http://foobar").unwrap();
+        assert!(srl.matches_pattern("foo*"));
+        assert!(srl.matches_pattern("http://foo*"));
+        assert!(!srl.matches_pattern("ftp://foo*"));
+        assert!(!SRL::new("foobar").unwrap().matches_pattern("http://*"));
+    }
+
+    #[test]
+    fn star_matches_only_letters() {
+        let options = SrlOptions {
+            allow_hyphen_in_address: true,
+        };
+        let srl = SRL::new_with_options("foo-bar", options).unwrap();
+        assert!(srl.matches_pattern("foo-*"));
+        assert!(srl.matches_pattern("*-bar"));
+        assert!(!srl.matches_pattern("foo*"));
+    }
+}
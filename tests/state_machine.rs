@@ -0,0 +1,143 @@
+//! Run this file with `cargo test --test state_machine`.
+
+//! TODO: Implement a generic finite-state machine called `StateMachine`, which is generic over
+//! the type of its states `S` and the type of the events `E` that move it between states.
+//!
+//! - `new`: creates a machine in the given initial state, without any transitions.
+//! - `add_transition`: registers that `event` moves the machine from state `from` to state `to`.
+//!   Adding a transition for an already registered `(from, event)` pair replaces it.
+//! - `transition`: applies an event to the current state and returns the new state. If no
+//!   transition is registered for the current state and the event, return an error and keep
+//!   the current state.
+//! - `current_state`: returns the current state.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::hash::Hash;
+
+#[derive(Debug, Eq, PartialEq)]
+enum StateMachineError {
+    UndefinedTransition,
+}
+
+impl Display for StateMachineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for StateMachineError {}
+
+struct StateMachine<S: Eq + Hash + Clone, E: Eq + Hash + Clone> {
+    current: S,
+    transitions: HashMap<(S, E), S>,
+}
+
+impl<S: Eq + Hash + Clone, E: Eq + Hash + Clone> StateMachine<S, E> {
+    fn new(initial: S) -> Self {
+        Self {
+            current: initial,
+            transitions: HashMap::new(),
+        }
+    }
+
+    fn add_transition(&mut self, from: S, event: E, to: S) {
+        self.transitions.insert((from, event), to);
+    }
+
+    fn transition(&mut self, event: E) -> Result<&S, StateMachineError> {
+        let next = self
+            .transitions
+            .get(&(self.current.clone(), event))
+            .ok_or(StateMachineError::UndefinedTransition)?;
+
+        self.current = next.clone();
+        Ok(&self.current)
+    }
+
+    fn current_state(&self) -> &S {
+        &self.current
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{StateMachine, StateMachineError};
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    enum Light {
+        Red,
+        Green,
+        Yellow,
+        FlashingYellow,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    enum Signal {
+        Timer,
+        Fault,
+        Reset,
+    }
+
+    fn traffic_light() -> StateMachine<Light, Signal> {
+        let mut machine = StateMachine::new(Light::Red);
+        machine.add_transition(Light::Red, Signal::Timer, Light::Green);
+        machine.add_transition(Light::Green, Signal::Timer, Light::Yellow);
+        machine.add_transition(Light::Yellow, Signal::Timer, Light::Red);
+        for light in [Light::Red, Light::Green, Light::Yellow] {
+            machine.add_transition(light, Signal::Fault, Light::FlashingYellow);
+        }
+        machine.add_transition(Light::FlashingYellow, Signal::Reset, Light::Red);
+        machine
+    }
+
+    #[test]
+    fn initial_state() {
+        let machine: StateMachine<Light, Signal> = StateMachine::new(Light::Red);
+        assert_eq!(machine.current_state(), &Light::Red);
+    }
+
+    #[test]
+    fn traffic_light_cycle() {
+        let mut machine = traffic_light();
+        assert_eq!(machine.transition(Signal::Timer), Ok(&Light::Green));
+        assert_eq!(machine.transition(Signal::Timer), Ok(&Light::Yellow));
+        assert_eq!(machine.transition(Signal::Timer), Ok(&Light::Red));
+        assert_eq!(machine.current_state(), &Light::Red);
+    }
+
+    #[test]
+    fn traffic_light_fault_and_reset() {
+        let mut machine = traffic_light();
+        machine.transition(Signal::Timer).unwrap();
+        assert_eq!(machine.transition(Signal::Fault), Ok(&Light::FlashingYellow));
+        assert_eq!(machine.transition(Signal::Reset), Ok(&Light::Red));
+    }
+
+    #[test]
+    fn invalid_transition_is_rejected() {
+        let mut machine = traffic_light();
+        assert_eq!(
+            machine.transition(Signal::Reset),
+            Err(StateMachineError::UndefinedTransition)
+        );
+        assert_eq!(machine.current_state(), &Light::Red);
+
+        machine.transition(Signal::Fault).unwrap();
+        assert_eq!(
+            machine.transition(Signal::Timer),
+            Err(StateMachineError::UndefinedTransition)
+        );
+        assert_eq!(machine.current_state(), &Light::FlashingYellow);
+    }
+
+    #[test]
+    fn replacing_a_transition() {
+        let mut machine = StateMachine::new("locked");
+        machine.add_transition("locked", "coin", "unlocked");
+        machine.add_transition("locked", "coin", "locked");
+        assert_eq!(machine.transition("coin"), Ok(&"locked"));
+    }
+}
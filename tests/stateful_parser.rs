@@ -0,0 +1,235 @@
+//! Run this file with `cargo test --test stateful_parser`.
+
+//! TODO: Implement a small parser that walks over a string and can be used as a building block
+//! for parsing simple grammars.
+//!
+//! The parser keeps the input and the byte position up to which it has consumed the input. Every
+//! `parse_*` method tries to consume something at the current position and returns the consumed
+//! part of the input. If it fails, it returns an error containing the position and leaves the
+//! position unchanged, so that the caller can try something else.
+//!
+//! - `parse_literal`: consumes exactly `expected`.
+//! - `parse_digits`: consumes one or more ASCII digits.
+//! - `parse_alpha`: consumes one or more ASCII letters.
+//! - `skip_whitespace`: consumes any amount of whitespace, possibly none.
+//! - `remaining`: returns the part of the input that was not consumed yet.
+//!
+//! Hint: the returned slices borrow from the input, not from the parser, so that they can be kept
+//! around while the parser continues.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq)]
+enum ParseError {
+    LiteralMismatch { position: usize, expected: String },
+    NoDigits { position: usize },
+    NoLetters { position: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for ParseError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    /// Byte offset of the first character that was not consumed yet.
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn remaining(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    /// Consumes the longest prefix of the remaining input whose characters satisfy `predicate`.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let remaining = self.remaining();
+        let len = remaining
+            .find(|c| !predicate(c))
+            .unwrap_or(remaining.len());
+        self.pos += len;
+        &remaining[..len]
+    }
+
+    fn parse_literal(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        let remaining = self.remaining();
+        if !remaining.starts_with(expected) {
+            return Err(ParseError::LiteralMismatch {
+                position: self.pos,
+                expected: expected.to_string(),
+            });
+        }
+        self.pos += expected.len();
+        Ok(&remaining[..expected.len()])
+    }
+
+    fn parse_digits(&mut self) -> Result<&'a str, ParseError> {
+        let position = self.pos;
+        match self.take_while(|c| c.is_ascii_digit()) {
+            "" => Err(ParseError::NoDigits { position }),
+            digits => Ok(digits),
+        }
+    }
+
+    fn parse_alpha(&mut self) -> Result<&'a str, ParseError> {
+        let position = self.pos;
+        match self.take_while(|c| c.is_ascii_alphabetic()) {
+            "" => Err(ParseError::NoLetters { position }),
+            letters => Ok(letters),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{ParseError, Parser};
+
+    /// Parses `let <name> = <number>;` statements, separated by any amount of whitespace.
+    fn parse_assignments(input: &str) -> Result<Vec<(&str, u64)>, ParseError> {
+        let mut parser = Parser::new(input);
+        let mut assignments = vec![];
+
+        parser.skip_whitespace();
+        while !parser.remaining().is_empty() {
+            parser.parse_literal("let")?;
+            parser.skip_whitespace();
+            let name = parser.parse_alpha()?;
+            parser.skip_whitespace();
+            parser.parse_literal("=")?;
+            parser.skip_whitespace();
+            let value = parser.parse_digits()?.parse().unwrap();
+            parser.parse_literal(";")?;
+            parser.skip_whitespace();
+            assignments.push((name, value));
+        }
+
+        Ok(assignments)
+    }
+
+    /// Parses a date like `2024-01-31` into its parts.
+    fn parse_date(input: &str) -> Result<(u32, u32, u32), ParseError> {
+        let mut parser = Parser::new(input);
+        let year = parser.parse_digits()?;
+        parser.parse_literal("-")?;
+        let month = parser.parse_digits()?;
+        parser.parse_literal("-")?;
+        let day = parser.parse_digits()?;
+        Ok((year.parse().unwrap(), month.parse().unwrap(), day.parse().unwrap()))
+    }
+
+    #[test]
+    fn literal() {
+        let mut parser = Parser::new("hello world");
+        assert_eq!(parser.parse_literal("hello"), Ok("hello"));
+        assert_eq!(parser.remaining(), " world");
+        assert_eq!(
+            parser.parse_literal("world"),
+            Err(ParseError::LiteralMismatch {
+                position: 5,
+                expected: "world".to_string()
+            })
+        );
+        assert_eq!(parser.remaining(), " world");
+        assert_eq!(parser.parse_literal(""), Ok(""));
+    }
+
+    #[test]
+    fn digits_and_letters() {
+        let mut parser = Parser::new("123abc!");
+        assert_eq!(parser.parse_alpha(), Err(ParseError::NoLetters { position: 0 }));
+        assert_eq!(parser.parse_digits(), Ok("123"));
+        assert_eq!(parser.parse_digits(), Err(ParseError::NoDigits { position: 3 }));
+        assert_eq!(parser.parse_alpha(), Ok("abc"));
+        assert_eq!(parser.remaining(), "!");
+    }
+
+    #[test]
+    fn whitespace() {
+        let mut parser = Parser::new(" \t\n x ");
+        parser.skip_whitespace();
+        assert_eq!(parser.remaining(), "x ");
+        parser.skip_whitespace();
+        assert_eq!(parser.remaining(), "x ");
+    }
+
+    #[test]
+    fn empty_input() {
+        let mut parser = Parser::new("");
+        parser.skip_whitespace();
+        assert_eq!(parser.remaining(), "");
+        assert_eq!(parser.parse_digits(), Err(ParseError::NoDigits { position: 0 }));
+    }
+
+    #[test]
+    fn non_ascii_input() {
+        let mut parser = Parser::new("straße 42");
+        assert_eq!(parser.parse_alpha(), Ok("stra"));
+        assert_eq!(parser.parse_literal("ß"), Ok("ß"));
+        assert_eq!(parser.parse_alpha(), Ok("e"));
+        parser.skip_whitespace();
+        assert_eq!(parser.parse_digits(), Ok("42"));
+    }
+
+    #[test]
+    fn results_outlive_parser() {
+        let input = String::from("abc 123");
+        let (letters, digits) = {
+            let mut parser = Parser::new(&input);
+            let letters = parser.parse_alpha().unwrap();
+            parser.skip_whitespace();
+            (letters, parser.parse_digits().unwrap())
+        };
+        assert_eq!((letters, digits), ("abc", "123"));
+    }
+
+    #[test]
+    fn grammar_assignments() {
+        assert_eq!(
+            parse_assignments("let x = 1;\n  let answer=42;  "),
+            Ok(vec![("x", 1), ("answer", 42)])
+        );
+        assert_eq!(parse_assignments("   "), Ok(vec![]));
+        assert_eq!(
+            parse_assignments("let x = 1; set y = 2;"),
+            Err(ParseError::LiteralMismatch {
+                position: 11,
+                expected: "let".to_string()
+            })
+        );
+        assert_eq!(
+            parse_assignments("let 1 = x;"),
+            Err(ParseError::NoLetters { position: 4 })
+        );
+        assert_eq!(
+            parse_assignments("let x = ;"),
+            Err(ParseError::NoDigits { position: 8 })
+        );
+    }
+
+    #[test]
+    fn grammar_date() {
+        assert_eq!(parse_date("2024-01-31"), Ok((2024, 1, 31)));
+        assert_eq!(
+            parse_date("2024/01/31"),
+            Err(ParseError::LiteralMismatch {
+                position: 4,
+                expected: "-".to_string()
+            })
+        );
+        assert_eq!(parse_date("2024--31"), Err(ParseError::NoDigits { position: 5 }));
+    }
+}
@@ -0,0 +1,178 @@
+//! Run this file with `cargo test --test string_calculator`.
+
+//! TODO: Implement a function called `add`, which sums the numbers contained in a string
+//! (the classic "string calculator" kata).
+//!
+//! - An empty string sums to `0`.
+//! - Numbers are separated by commas or newlines, e.g. `"1\n2,3"` sums to `6`.
+//! - The string may start with a header that defines additional delimiters, followed by a
+//!   newline:
+//!   - `"//;\n1;2"` uses `;` as a delimiter.
+//!   - `"//[***]\n1***2"` uses a delimiter of any length, written in brackets.
+//!   - `"//[*][%]\n1*2%3"` defines several delimiters at once.
+//! - Negative numbers are not allowed. If there are any, return an error listing all of them.
+//! - Anything between delimiters that is not a number is an error, including an empty string.
+//! - A sum that does not fit into an `i64` is an error.
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq)]
+enum CalcError {
+    NegativeNumbers(Vec<i64>),
+    InvalidNumber(String),
+    InvalidHeader,
+    Overflow,
+}
+
+impl Display for CalcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Error for CalcError {}
+
+/// Splits off the delimiter header, returning the custom delimiters and the rest of the input.
+fn parse_header(numbers: &str) -> Result<(Vec<&str>, &str), CalcError> {
+    let Some(rest) = numbers.strip_prefix("//") else {
+        return Ok((vec![], numbers));
+    };
+    let (header, body) = rest.split_once('\n').ok_or(CalcError::InvalidHeader)?;
+
+    if !header.starts_with('[') {
+        if header.is_empty() {
+            return Err(CalcError::InvalidHeader);
+        }
+        return Ok((vec![header], body));
+    }
+
+    let inner = header
+        .strip_prefix('[')
+        .and_then(|h| h.strip_suffix(']'))
+        .ok_or(CalcError::InvalidHeader)?;
+    let delimiters: Vec<&str> = inner.split("][").collect();
+    if delimiters.iter().any(|d| d.is_empty()) {
+        return Err(CalcError::InvalidHeader);
+    }
+
+    Ok((delimiters, body))
+}
+
+fn add(numbers: &str) -> Result<i64, CalcError> {
+    let (mut delimiters, body) = parse_header(numbers)?;
+    if body.is_empty() {
+        return Ok(0);
+    }
+
+    // Replace longer delimiters first, so that e.g. `**` is not split by `*`.
+    delimiters.sort_by_key(|d| std::cmp::Reverse(d.len()));
+    let mut normalized = body.replace('\n', ",");
+    for delimiter in delimiters {
+        normalized = normalized.replace(delimiter, ",");
+    }
+
+    let values = normalized
+        .split(',')
+        .map(|token| {
+            token
+                .parse::<i64>()
+                .map_err(|_| CalcError::InvalidNumber(token.to_string()))
+        })
+        .collect::<Result<Vec<i64>, CalcError>>()?;
+
+    let negatives: Vec<i64> = values.iter().copied().filter(|v| *v < 0).collect();
+    if !negatives.is_empty() {
+        return Err(CalcError::NegativeNumbers(negatives));
+    }
+
+    values
+        .iter()
+        .try_fold(0i64, |sum, &value| sum.checked_add(value))
+        .ok_or(CalcError::Overflow)
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{add, CalcError};
+
+    #[test]
+    fn empty_string() {
+        assert_eq!(add(""), Ok(0));
+        assert_eq!(add("//;\n"), Ok(0));
+    }
+
+    #[test]
+    fn single_number() {
+        assert_eq!(add("7"), Ok(7));
+    }
+
+    #[test]
+    fn comma_separated() {
+        assert_eq!(add("1,2"), Ok(3));
+        assert_eq!(add("1,2,3,4,5"), Ok(15));
+    }
+
+    #[test]
+    fn newline_separated() {
+        assert_eq!(add("1\n2,3"), Ok(6));
+        assert_eq!(add("10\n20\n30"), Ok(60));
+    }
+
+    #[test]
+    fn custom_delimiter() {
+        assert_eq!(add("//;\n1;2"), Ok(3));
+        assert_eq!(add("//;\n1;2,3\n4"), Ok(10));
+        assert_eq!(add("//sep\n2sep3"), Ok(5));
+    }
+
+    #[test]
+    fn bracketed_delimiter() {
+        assert_eq!(add("//[***]\n1***2***3"), Ok(6));
+    }
+
+    #[test]
+    fn multiple_delimiters() {
+        assert_eq!(add("//[*][%]\n1*2%3"), Ok(6));
+        assert_eq!(add("//[*][**]\n1**2*3"), Ok(6));
+    }
+
+    #[test]
+    fn negative_numbers() {
+        assert_eq!(add("1,-2"), Err(CalcError::NegativeNumbers(vec![-2])));
+        assert_eq!(
+            add("-1,2\n-3,-4"),
+            Err(CalcError::NegativeNumbers(vec![-1, -3, -4]))
+        );
+        assert_eq!(
+            add("//;\n-5;6;-7"),
+            Err(CalcError::NegativeNumbers(vec![-5, -7]))
+        );
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        assert_eq!(add("1,a"), Err(CalcError::InvalidNumber("a".to_string())));
+        assert_eq!(add("1,\n2"), Err(CalcError::InvalidNumber("".to_string())));
+        assert_eq!(add("1;2"), Err(CalcError::InvalidNumber("1;2".to_string())));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(add("9223372036854775807,1"), Err(CalcError::Overflow));
+        assert_eq!(add("9223372036854775807,0"), Ok(i64::MAX));
+        assert_eq!(
+            add("4611686018427387904\n4611686018427387904"),
+            Err(CalcError::Overflow)
+        );
+    }
+
+    #[test]
+    fn invalid_header() {
+        assert_eq!(add("//;1;2"), Err(CalcError::InvalidHeader));
+        assert_eq!(add("//\n1"), Err(CalcError::InvalidHeader));
+        assert_eq!(add("//[]\n1"), Err(CalcError::InvalidHeader));
+        assert_eq!(add("//[*\n1"), Err(CalcError::InvalidHeader));
+    }
+}
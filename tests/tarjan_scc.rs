@@ -0,0 +1,184 @@
+//! Run this file with `cargo test --test tarjan_scc`.
+
+//! TODO: Implement a function called `strongly_connected_components`, which splits the nodes
+//! `0..n` of a directed graph into its
+//! [strongly connected components](https://en.wikipedia.org/wiki/Strongly_connected_component)
+//! using [Tarjan's algorithm](https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm).
+//!
+//! Two nodes are in the same component if each of them can be reached from the other. The graph
+//! is given as a map from a node to the nodes its outgoing edges lead to, nodes without outgoing
+//! edges may be missing from the map.
+//!
+//! Start the depth-first searches at the nodes in increasing order and follow the edges in the
+//! order in which they are listed. Return the components in the order in which the algorithm
+//! finds them (this is a reverse topological order: no component has an edge to a component
+//! listed after it), with the nodes of every component sorted.
+//!
+//! Hint: during the depth-first search, keep the visited nodes on a stack and remember for every
+//! node its discovery index and the smallest discovery index reachable from it ("low-link"). A
+//! node whose low-link equals its own index is the root of a component, which consists of it and
+//! all nodes above it on the stack.
+
+use std::collections::HashMap;
+
+struct Tarjan<'a> {
+    adjacency: &'a HashMap<usize, Vec<usize>>,
+    /// The discovery index of every node, or `None` if it was not visited yet.
+    index: Vec<Option<usize>>,
+    low_link: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+    next_index: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, node: usize) {
+        self.index[node] = Some(self.next_index);
+        self.low_link[node] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        let adjacency = self.adjacency;
+        for &successor in adjacency.get(&node).into_iter().flatten() {
+            match self.index[successor] {
+                None => {
+                    self.visit(successor);
+                    self.low_link[node] = self.low_link[node].min(self.low_link[successor]);
+                }
+                Some(index) if self.on_stack[successor] => {
+                    self.low_link[node] = self.low_link[node].min(index);
+                }
+                // The successor belongs to a component that was already completed.
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low_link[node]) == self.index[node] {
+            let mut component = vec![];
+            loop {
+                let member = self.stack.pop().unwrap();
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            component.sort();
+            self.components.push(component);
+        }
+    }
+}
+
+fn strongly_connected_components(
+    adjacency: &HashMap<usize, Vec<usize>>,
+    n: usize,
+) -> Vec<Vec<usize>> {
+    let mut tarjan = Tarjan {
+        adjacency,
+        index: vec![None; n],
+        low_link: vec![0; n],
+        stack: vec![],
+        on_stack: vec![false; n],
+        next_index: 0,
+        components: vec![],
+    };
+
+    for node in 0..n {
+        if tarjan.index[node].is_none() {
+            tarjan.visit(node);
+        }
+    }
+
+    tarjan.components
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::strongly_connected_components;
+    use std::collections::HashMap;
+
+    fn graph(edges: &[(usize, usize)]) -> HashMap<usize, Vec<usize>> {
+        let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+        for &(from, to) in edges {
+            adjacency.entry(from).or_default().push(to);
+        }
+        adjacency
+    }
+
+    /// Checks that no component has an edge to a component listed after it.
+    fn assert_reverse_topological(edges: &[(usize, usize)], components: &[Vec<usize>]) {
+        let position = |node| components.iter().position(|c| c.contains(&node)).unwrap();
+        for &(from, to) in edges {
+            assert!(position(from) >= position(to), "edge {from} -> {to}");
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        assert!(strongly_connected_components(&HashMap::new(), 0).is_empty());
+    }
+
+    #[test]
+    fn isolated_nodes() {
+        assert_eq!(
+            strongly_connected_components(&HashMap::new(), 3),
+            vec![vec![0], vec![1], vec![2]]
+        );
+    }
+
+    #[test]
+    fn single_cycle() {
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 0)];
+        assert_eq!(
+            strongly_connected_components(&graph(&edges), 4),
+            vec![vec![0, 1, 2, 3]]
+        );
+    }
+
+    #[test]
+    fn dag() {
+        let edges = [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)];
+        let components = strongly_connected_components(&graph(&edges), 5);
+        assert_eq!(components, vec![vec![4], vec![3], vec![1], vec![2], vec![0]]);
+        assert_reverse_topological(&edges, &components);
+    }
+
+    #[test]
+    fn two_components() {
+        // Two cycles, connected by an edge in one direction only.
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)];
+        let components = strongly_connected_components(&graph(&edges), 5);
+        assert_eq!(components, vec![vec![3, 4], vec![0, 1, 2]]);
+        assert_reverse_topological(&edges, &components);
+    }
+
+    #[test]
+    fn self_loops_and_nested_cycles() {
+        let edges = [
+            (0, 0),
+            (0, 1),
+            (1, 2),
+            (2, 1),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (5, 4),
+            (4, 5),
+            (6, 3),
+        ];
+        let components = strongly_connected_components(&graph(&edges), 7);
+        assert_eq!(components, vec![vec![4, 5], vec![1, 2, 3], vec![0], vec![6]]);
+        assert_reverse_topological(&edges, &components);
+    }
+
+    #[test]
+    fn long_cycle() {
+        let n = 1000;
+        let edges: Vec<_> = (0..n).map(|i| (i, (i + 1) % n)).collect();
+        let components = strongly_connected_components(&graph(&edges), n);
+        assert_eq!(components, vec![(0..n).collect::<Vec<_>>()]);
+    }
+}
@@ -0,0 +1,162 @@
+//! Run this file with `cargo test --test traveling_salesman`.
+
+//! TODO: Solve the [travelling salesman problem](https://en.wikipedia.org/wiki/Travelling_salesman_problem):
+//! find the length of the shortest round trip that visits every city exactly once and returns to
+//! the city it started from.
+//!
+//! `distances[i][j]` is the length of the way from city `i` to city `j`, which may differ from
+//! the way back. With no cities or a single city, the shortest round trip has length 0.
+//!
+//! - `tsp_brute`: tries every order of the cities. This takes O(n!) time, so it is only feasible
+//!   for about 10 cities.
+//! - `tsp_dp`: uses the [Held-Karp algorithm](https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm),
+//!   which takes O(n² · 2ⁿ) time and is feasible for about 20 cities.
+//!
+//! Hint: a round trip can start anywhere, so let it start at city 0. For Held-Karp, let
+//! `best[set][j]` be the length of the shortest path that starts at city 0, visits exactly the
+//! cities in `set` and ends at city `j` (which is in `set`). Represent `set` as a bitmask.
+
+fn tsp_brute(distances: &[Vec<u64>]) -> u64 {
+    /// Extends the path ending at `last` by every unvisited city, returning the shortest round
+    /// trip length found.
+    fn search(distances: &[Vec<u64>], visited: &mut [bool], last: usize, length: u64) -> u64 {
+        let mut best = None;
+        for next in 0..distances.len() {
+            if !visited[next] {
+                visited[next] = true;
+                let total = search(distances, visited, next, length + distances[last][next]);
+                visited[next] = false;
+                best = Some(best.map_or(total, |best: u64| best.min(total)));
+            }
+        }
+        // Every city was visited, return to the start.
+        best.unwrap_or(length + distances[last][0])
+    }
+
+    if distances.len() <= 1 {
+        return 0;
+    }
+
+    let mut visited = vec![false; distances.len()];
+    visited[0] = true;
+    search(distances, &mut visited, 0, 0)
+}
+
+fn tsp_dp(distances: &[Vec<u64>]) -> u64 {
+    let n = distances.len();
+    if n <= 1 {
+        return 0;
+    }
+
+    // City 0 is always the start, so the sets only contain the cities `1..n`, city `j` being
+    // bit `j - 1`.
+    let sets = 1 << (n - 1);
+    let mut best = vec![vec![u64::MAX; n]; sets];
+    for j in 1..n {
+        best[1 << (j - 1)][j] = distances[0][j];
+    }
+
+    for set in 1..sets {
+        for last in 1..n {
+            let length = best[set][last];
+            if length == u64::MAX {
+                continue;
+            }
+            for next in 1..n {
+                let bit = 1 << (next - 1);
+                if set & bit == 0 {
+                    let extended = &mut best[set | bit][next];
+                    *extended = (*extended).min(length + distances[last][next]);
+                }
+            }
+        }
+    }
+
+    (1..n)
+        .map(|last| best[sets - 1][last] + distances[last][0])
+        .min()
+        .unwrap()
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{tsp_brute, tsp_dp};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_distances(rng: &mut StdRng, n: usize, symmetric: bool) -> Vec<Vec<u64>> {
+        let random: Vec<Vec<u64>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 0 } else { rng.random_range(1..100) }).collect())
+            .collect();
+        // A symmetric instance mirrors the upper triangle.
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if symmetric && j < i { random[j][i] } else { random[i][j] })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn trivial_instances() {
+        assert_eq!(tsp_brute(&[]), 0);
+        assert_eq!(tsp_dp(&[]), 0);
+        assert_eq!(tsp_brute(&[vec![0]]), 0);
+        assert_eq!(tsp_dp(&[vec![0]]), 0);
+    }
+
+    #[test]
+    fn two_cities() {
+        let distances = [vec![0, 3], vec![5, 0]];
+        assert_eq!(tsp_brute(&distances), 8);
+        assert_eq!(tsp_dp(&distances), 8);
+    }
+
+    #[test]
+    fn classic_four_cities() {
+        let distances = [
+            vec![0, 10, 15, 20],
+            vec![10, 0, 35, 25],
+            vec![15, 35, 0, 30],
+            vec![20, 25, 30, 0],
+        ];
+        assert_eq!(tsp_brute(&distances), 80);
+        assert_eq!(tsp_dp(&distances), 80);
+    }
+
+    #[test]
+    fn asymmetric_distances() {
+        // Going around clockwise is cheap, counter-clockwise is expensive.
+        let distances = [
+            vec![0, 1, 50, 50],
+            vec![50, 0, 1, 50],
+            vec![50, 50, 0, 1],
+            vec![1, 50, 50, 0],
+        ];
+        assert_eq!(tsp_brute(&distances), 4);
+        assert_eq!(tsp_dp(&distances), 4);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        let mut rng = StdRng::seed_from_u64(17);
+        for n in 2..=8 {
+            for symmetric in [true, false] {
+                let distances = random_distances(&mut rng, n, symmetric);
+                assert_eq!(tsp_dp(&distances), tsp_brute(&distances), "{distances:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn dp_handles_larger_instances() {
+        // Cities on a line: the best round trip goes to one end and back.
+        let n = 16;
+        let distances: Vec<Vec<u64>> = (0..n)
+            .map(|i: u64| (0..n).map(|j: u64| i.abs_diff(j)).collect())
+            .collect();
+        assert_eq!(tsp_dp(&distances), 2 * (n - 1));
+    }
+}
@@ -0,0 +1,167 @@
+//! Run this file with `cargo test --test trie`.
+
+//! TODO: Implement a [trie](https://en.wikipedia.org/wiki/Trie) (prefix tree) storing a set of
+//! words, with one node per character.
+//!
+//! - `insert`: adds a word to the trie, inserting a word twice stores it once.
+//! - `contains`: returns whether the exact word was inserted.
+//! - `starts_with`: returns whether any inserted word begins with `prefix`.
+//! - `autocomplete`: returns up to `max_results` inserted words that begin with `prefix`, sorted
+//!   lexicographically. A word counts as a completion of itself.
+//!
+//! `autocomplete` should walk down to the node of the prefix and then traverse its subtree
+//! depth-first, stopping as soon as it has found enough words. If the children of every node are
+//! kept sorted (e.g. in a `BTreeMap`), a pre-order traversal finds the words in sorted order.
+
+use std::collections::BTreeMap;
+
+#[derive(Default)]
+struct Node {
+    children: BTreeMap<char, Node>,
+    is_word: bool,
+}
+
+#[derive(Default)]
+struct Trie {
+    root: Node,
+}
+
+impl Trie {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_word = true;
+    }
+
+    /// Returns the node reached by following `prefix` from the root, if there is one.
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.is_word)
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    fn autocomplete(&self, prefix: &str, max_results: usize) -> Vec<String> {
+        let mut results = vec![];
+        if let Some(node) = self.find(prefix) {
+            let mut word = prefix.to_string();
+            collect_words(node, &mut word, max_results, &mut results);
+        }
+        results
+    }
+}
+
+/// Appends the words below `node` to `results` in pre-order, until there are `max_results` of
+/// them. `word` holds the characters on the path to `node`.
+fn collect_words(node: &Node, word: &mut String, max_results: usize, results: &mut Vec<String>) {
+    if results.len() >= max_results {
+        return;
+    }
+    if node.is_word {
+        results.push(word.clone());
+    }
+
+    for (&c, child) in &node.children {
+        if results.len() >= max_results {
+            return;
+        }
+        word.push(c);
+        collect_words(child, word, max_results, results);
+        word.pop();
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::Trie;
+
+    fn sample_trie() -> Trie {
+        let mut trie = Trie::new();
+        for word in ["tea", "ten", "to", "inn", "tear", "team", "in", "ted", "i", "tea"] {
+            trie.insert(word);
+        }
+        trie
+    }
+
+    #[test]
+    fn contains_inserted_words() {
+        let trie = sample_trie();
+        assert!(trie.contains("tea"));
+        assert!(trie.contains("i"));
+        assert!(trie.contains("inn"));
+        assert!(!trie.contains("te"));
+        assert!(!trie.contains("tears"));
+        assert!(!trie.contains(""));
+    }
+
+    #[test]
+    fn starts_with_prefix() {
+        let trie = sample_trie();
+        assert!(trie.starts_with("te"));
+        assert!(trie.starts_with("tea"));
+        assert!(trie.starts_with(""));
+        assert!(!trie.starts_with("x"));
+        assert!(!trie.starts_with("teams"));
+    }
+
+    #[test]
+    fn autocomplete_sorted() {
+        let trie = sample_trie();
+        assert_eq!(
+            trie.autocomplete("te", 10),
+            vec!["tea", "team", "tear", "ted", "ten"]
+        );
+        assert_eq!(trie.autocomplete("i", 10), vec!["i", "in", "inn"]);
+    }
+
+    #[test]
+    fn autocomplete_everything() {
+        let trie = sample_trie();
+        assert_eq!(
+            trie.autocomplete("", 100),
+            vec!["i", "in", "inn", "tea", "team", "tear", "ted", "ten", "to"]
+        );
+    }
+
+    #[test]
+    fn autocomplete_respects_limit() {
+        let trie = sample_trie();
+        assert_eq!(trie.autocomplete("te", 2), vec!["tea", "team"]);
+        assert_eq!(trie.autocomplete("", 4), vec!["i", "in", "inn", "tea"]);
+        assert!(trie.autocomplete("te", 0).is_empty());
+    }
+
+    #[test]
+    fn autocomplete_unknown_prefix() {
+        let trie = sample_trie();
+        assert!(trie.autocomplete("x", 10).is_empty());
+        assert!(trie.autocomplete("teams", 10).is_empty());
+        assert!(Trie::new().autocomplete("", 10).is_empty());
+    }
+
+    #[test]
+    fn unicode_words() {
+        let mut trie = Trie::new();
+        trie.insert("čaj");
+        trie.insert("čas");
+        trie.insert("cesta");
+        assert_eq!(trie.autocomplete("č", 10), vec!["čaj", "čas"]);
+        assert_eq!(trie.autocomplete("", 10), vec!["cesta", "čaj", "čas"]);
+    }
+}
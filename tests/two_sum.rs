@@ -0,0 +1,153 @@
+//! Run this file with `cargo test --test two_sum`.
+
+//! TODO: Implement the classic `two_sum` and `three_sum` problems.
+//!
+//! - `two_sum`: returns the indices `(i, j)` with `i < j` of two values that add up to `target`,
+//!   or `None` if there are no such values. If there are several pairs, return the one whose
+//!   second index `j` is the smallest. Solve it in O(n) time by remembering the index of every
+//!   value seen so far in a `HashMap`.
+//! - `three_sum`: returns all unique triplets of values (at distinct positions) that add up to
+//!   zero. Every triplet is sorted in ascending order, and the triplets are listed in ascending
+//!   order as well. Solve it in O(n^2) time by sorting the values first and then, for every first
+//!   value, looking for the other two with two pointers moving towards each other.
+//!
+//! Beware of overflow, the sum of two `i32` values does not always fit into an `i32`.
+
+use std::collections::HashMap;
+
+fn two_sum(values: &[i32], target: i32) -> Option<(usize, usize)> {
+    let mut seen: HashMap<i64, usize> = HashMap::new();
+
+    for (j, &value) in values.iter().enumerate() {
+        let complement = target as i64 - value as i64;
+        if let Some(&i) = seen.get(&complement) {
+            return Some((i, j));
+        }
+        // Keep the first index of every value.
+        seen.entry(value as i64).or_insert(j);
+    }
+
+    None
+}
+
+fn three_sum(values: &[i32]) -> Vec<(i32, i32, i32)> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    let mut triplets = vec![];
+    for first in 0..sorted.len() {
+        if first > 0 && sorted[first] == sorted[first - 1] {
+            continue;
+        }
+
+        let (mut low, mut high) = (first + 1, sorted.len().saturating_sub(1));
+        while low < high {
+            let sum = sorted[first] as i64 + sorted[low] as i64 + sorted[high] as i64;
+            match sum.cmp(&0) {
+                std::cmp::Ordering::Less => low += 1,
+                std::cmp::Ordering::Greater => high -= 1,
+                std::cmp::Ordering::Equal => {
+                    triplets.push((sorted[first], sorted[low], sorted[high]));
+                    // Skip duplicates of the second value, the third one is then implied.
+                    while low < high && sorted[low] == sorted[low + 1] {
+                        low += 1;
+                    }
+                    low += 1;
+                    high -= 1;
+                }
+            }
+        }
+    }
+
+    triplets
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::{three_sum, two_sum};
+    use std::collections::HashSet;
+
+    #[test]
+    fn two_sum_found() {
+        assert_eq!(two_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+        assert_eq!(two_sum(&[3, 2, 4], 6), Some((1, 2)));
+        assert_eq!(two_sum(&[3, 3], 6), Some((0, 1)));
+        assert_eq!(two_sum(&[-4, 10, 1, 8], 4), Some((0, 3)));
+    }
+
+    #[test]
+    fn two_sum_smallest_second_index() {
+        assert_eq!(two_sum(&[1, 5, 4, 2, 3], 6), Some((0, 1)));
+        assert_eq!(two_sum(&[4, 1, 2, 3, 2], 4), Some((1, 3)));
+    }
+
+    #[test]
+    fn two_sum_not_found() {
+        assert_eq!(two_sum(&[], 0), None);
+        assert_eq!(two_sum(&[5], 10), None);
+        assert_eq!(two_sum(&[1, 2, 3], 7), None);
+    }
+
+    #[test]
+    fn two_sum_no_overflow() {
+        assert_eq!(two_sum(&[i32::MAX, 1, -i32::MAX], 0), Some((0, 2)));
+        assert_eq!(two_sum(&[i32::MAX, 1], i32::MIN), None);
+        assert_eq!(two_sum(&[i32::MIN, -1, i32::MAX], -1), Some((0, 2)));
+    }
+
+    #[test]
+    fn three_sum_example() {
+        assert_eq!(
+            three_sum(&[-1, 0, 1, 2, -1, -4]),
+            vec![(-1, -1, 2), (-1, 0, 1)]
+        );
+    }
+
+    #[test]
+    fn three_sum_zeros() {
+        assert_eq!(three_sum(&[0, 0, 0, 0, 0]), vec![(0, 0, 0)]);
+        assert!(three_sum(&[0, 0]).is_empty());
+    }
+
+    #[test]
+    fn three_sum_empty_result() {
+        assert!(three_sum(&[]).is_empty());
+        assert!(three_sum(&[1, 2, 3]).is_empty());
+        assert!(three_sum(&[-1, -2, 5, 7]).is_empty());
+        assert!(three_sum(&[i32::MAX, i32::MAX, 2]).is_empty());
+    }
+
+    #[test]
+    fn three_sum_unique_and_correct() {
+        let values = [-4, -2, -2, -2, 0, 1, 2, 2, 2, 3, 3, 4, 4, 6, 6];
+        let triplets = three_sum(&values);
+
+        let unique: HashSet<_> = triplets.iter().collect();
+        assert_eq!(unique.len(), triplets.len());
+
+        let mut sorted = triplets.clone();
+        sorted.sort();
+        assert_eq!(sorted, triplets);
+
+        for &(a, b, c) in &triplets {
+            assert!(a <= b && b <= c);
+            assert_eq!(a + b + c, 0);
+        }
+
+        // Compare with checking every combination of positions.
+        let mut expected = HashSet::new();
+        for i in 0..values.len() {
+            for j in i + 1..values.len() {
+                for k in j + 1..values.len() {
+                    if values[i] + values[j] + values[k] == 0 {
+                        let mut triplet = [values[i], values[j], values[k]];
+                        triplet.sort();
+                        expected.insert((triplet[0], triplet[1], triplet[2]));
+                    }
+                }
+            }
+        }
+        assert_eq!(unique, expected.iter().collect());
+    }
+}
@@ -0,0 +1,150 @@
+//! Run this file with `cargo test --test union_find`.
+
+//! TODO: Implement a [disjoint-set](https://en.wikipedia.org/wiki/Disjoint-set_data_structure)
+//! (union-find) data structure over the elements `0..n`.
+//!
+//! - `new`: creates `n` singleton sets.
+//! - `find`: returns the representative of the set containing `x`. Use path compression: after
+//!   finding the root, point every element on the way directly at it.
+//! - `union`: merges the sets containing `x` and `y`. Use union by rank: attach the root of the
+//!   shallower tree below the root of the deeper one.
+//! - `connected`: returns whether `x` and `y` are in the same set.
+//!
+//! Elements outside of `0..n` should cause a panic.
+
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = x;
+        while current != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    fn union(&mut self, x: usize, y: usize) {
+        let (x_root, y_root) = (self.find(x), self.find(y));
+        if x_root == y_root {
+            return;
+        }
+
+        match self.rank[x_root].cmp(&self.rank[y_root]) {
+            std::cmp::Ordering::Less => self.parent[x_root] = y_root,
+            std::cmp::Ordering::Greater => self.parent[y_root] = x_root,
+            std::cmp::Ordering::Equal => {
+                self.parent[y_root] = x_root;
+                self.rank[x_root] += 1;
+            }
+        }
+    }
+
+    fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::UnionFind;
+
+    #[test]
+    fn initially_disjoint() {
+        let mut sets = UnionFind::new(5);
+        for x in 0..5 {
+            assert_eq!(sets.find(x), x);
+            for y in 0..5 {
+                assert_eq!(sets.connected(x, y), x == y);
+            }
+        }
+    }
+
+    #[test]
+    fn union_connects() {
+        let mut sets = UnionFind::new(10);
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(1, 3);
+        sets.union(5, 6);
+        sets.union(7, 7);
+
+        assert!(sets.connected(0, 3));
+        assert!(sets.connected(2, 1));
+        assert!(sets.connected(6, 5));
+        assert!(!sets.connected(0, 5));
+        assert!(!sets.connected(4, 0));
+        assert!(!sets.connected(7, 8));
+    }
+
+    #[test]
+    fn union_is_transitive() {
+        let mut sets = UnionFind::new(100);
+        for x in 0..99 {
+            sets.union(x, x + 1);
+        }
+        assert!(sets.connected(0, 99));
+        assert!((0..100).all(|x| sets.find(x) == sets.find(0)));
+    }
+
+    #[test]
+    fn find_is_idempotent() {
+        let mut sets = UnionFind::new(8);
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(0, 2);
+        sets.union(4, 5);
+        sets.union(6, 4);
+
+        for x in 0..8 {
+            let root = sets.find(x);
+            assert_eq!(sets.find(x), root);
+            assert_eq!(sets.find(root), root);
+        }
+    }
+
+    #[test]
+    fn path_compression() {
+        let mut sets = UnionFind {
+            parent: vec![0, 0, 1, 2, 3],
+            rank: vec![4, 3, 2, 1, 0],
+        };
+        assert_eq!(sets.find(4), 0);
+        assert_eq!(sets.parent, vec![0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn union_by_rank() {
+        let mut sets = UnionFind::new(4);
+        sets.union(0, 1);
+        sets.union(2, 0);
+        let root = sets.find(0);
+        assert_eq!(root, sets.find(2));
+        assert_eq!(sets.rank[root], 1);
+        assert_eq!(sets.parent[2], root);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        UnionFind::new(3).find(3);
+    }
+}
@@ -0,0 +1,173 @@
+//! Run this file with `cargo test --test vector_2d`.
+
+//! TODO: Implement a two-dimensional vector type `Vec2D` with the usual arithmetic.
+//!
+//! - `+` and `-` work component-wise, `* f64` scales both components.
+//! - `dot`: the [dot product](https://en.wikipedia.org/wiki/Dot_product) `x1 * x2 + y1 * y2`.
+//! - `cross`: the z component of the cross product of the two vectors extended to 3D, i.e.
+//!   `x1 * y2 - y1 * x2`. It is positive if `other` lies counter-clockwise from `self`.
+//! - `magnitude`: the length of the vector.
+//! - `normalize`: the vector scaled to length 1, or `None` for the zero vector, which has no
+//!   direction.
+//! - `angle_to`: the signed angle in radians (between -π and π) by which `self` has to be rotated
+//!   counter-clockwise to point in the direction of `other`.
+//!
+//! Hint: `f64::atan2` computes an angle from the sine and cosine components, and the cross and
+//! dot products are exactly those (scaled by the magnitudes).
+
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Vec2D {
+    x: f64,
+    y: f64,
+}
+
+impl Vec2D {
+    fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    fn dot(&self, other: &Vec2D) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    fn cross(&self, other: &Vec2D) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    fn normalize(&self) -> Option<Vec2D> {
+        let magnitude = self.magnitude();
+        if magnitude == 0.0 {
+            return None;
+        }
+        Some(*self * (1.0 / magnitude))
+    }
+
+    fn angle_to(&self, other: &Vec2D) -> f64 {
+        self.cross(other).atan2(self.dot(other))
+    }
+}
+
+impl Add for Vec2D {
+    type Output = Vec2D;
+
+    fn add(self, other: Vec2D) -> Vec2D {
+        Vec2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2D {
+    type Output = Vec2D;
+
+    fn sub(self, other: Vec2D) -> Vec2D {
+        Vec2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Vec2D {
+    type Output = Vec2D;
+
+    fn mul(self, factor: f64) -> Vec2D {
+        Vec2D::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Below you can find a set of unit tests.
+#[cfg(test)]
+mod tests {
+    use crate::Vec2D;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    const EPSILON: f64 = 1e-9;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < EPSILON, "{actual} != {expected}");
+    }
+
+    fn samples() -> Vec<Vec2D> {
+        vec![
+            Vec2D::new(1.0, 0.0),
+            Vec2D::new(0.0, 2.0),
+            Vec2D::new(3.0, 4.0),
+            Vec2D::new(-1.5, 2.5),
+            Vec2D::new(-7.0, -0.25),
+            Vec2D::new(0.1, -9.0),
+        ]
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2D::new(1.0, 2.0);
+        let b = Vec2D::new(3.0, -4.0);
+        assert_eq!(a + b, Vec2D::new(4.0, -2.0));
+        assert_eq!(a - b, Vec2D::new(-2.0, 6.0));
+        assert_eq!(a * 2.5, Vec2D::new(2.5, 5.0));
+        assert_eq!(a - a, Vec2D::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn products() {
+        let a = Vec2D::new(1.0, 2.0);
+        let b = Vec2D::new(3.0, 4.0);
+        assert_eq!(a.dot(&b), 11.0);
+        assert_eq!(a.cross(&b), -2.0);
+        assert_eq!(b.cross(&a), 2.0);
+    }
+
+    #[test]
+    fn magnitude() {
+        assert_eq!(Vec2D::new(3.0, 4.0).magnitude(), 5.0);
+        assert_eq!(Vec2D::new(0.0, 0.0).magnitude(), 0.0);
+        assert_eq!(Vec2D::new(-5.0, 12.0).magnitude(), 13.0);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(Vec2D::new(0.0, 0.0).normalize(), None);
+        assert_eq!(Vec2D::new(0.0, -3.0).normalize(), Some(Vec2D::new(0.0, -1.0)));
+        for v in samples() {
+            let unit = v.normalize().unwrap();
+            assert_close(unit.magnitude(), 1.0);
+            assert_close(unit.cross(&v), 0.0);
+            assert!(unit.dot(&v) > 0.0);
+        }
+    }
+
+    #[test]
+    fn angles() {
+        let x = Vec2D::new(1.0, 0.0);
+        assert_close(x.angle_to(&Vec2D::new(0.0, 5.0)), FRAC_PI_2);
+        assert_close(x.angle_to(&Vec2D::new(0.0, -5.0)), -FRAC_PI_2);
+        assert_close(x.angle_to(&Vec2D::new(2.0, 2.0)), FRAC_PI_4);
+        assert_close(x.angle_to(&Vec2D::new(-1.0, 0.0)).abs(), PI);
+        assert_close(x.angle_to(&x), 0.0);
+    }
+
+    #[test]
+    fn geometric_identities() {
+        for a in samples() {
+            // A vector is orthogonal to itself rotated by 90 degrees, and parallel to itself.
+            let rotated = Vec2D::new(-a.y, a.x);
+            assert_close(a.dot(&rotated), 0.0);
+            assert_close(a.cross(&a), 0.0);
+            assert_close(a.dot(&a), a.magnitude() * a.magnitude());
+
+            for b in samples() {
+                assert_close(a.dot(&b), b.dot(&a));
+                assert_close(a.cross(&b), -b.cross(&a));
+                // Lagrange's identity: |a|²|b|² = (a·b)² + (a×b)².
+                let lhs = a.dot(&a) * b.dot(&b);
+                let rhs = a.dot(&b).powi(2) + a.cross(&b).powi(2);
+                assert!((lhs - rhs).abs() < EPSILON * lhs.max(1.0));
+                assert!((a + b).magnitude() <= a.magnitude() + b.magnitude() + EPSILON);
+                assert_close(a.angle_to(&b), -b.angle_to(&a));
+                assert_close(a.angle_to(&b).cos() * a.magnitude() * b.magnitude(), a.dot(&b));
+            }
+        }
+    }
+}